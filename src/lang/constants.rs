@@ -1,2 +1,19 @@
 pub const NYX_OK: i32 = 0;
+pub const NYX_ERROR: i32 = 1;
 pub const NYX_FILE_SUFFIX: &str = ".nx";
+pub const NYX_MAX_STRING_LENGTH: usize = 64 * 1024 * 1024;
+pub const NYX_MAX_LIST_LENGTH: usize = 4 * 1024 * 1024;
+pub const NYX_DEFAULT_EPSILON: f64 = 1e-9;
+pub const NYX_MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+pub const NYX_ENTRY_POINT: &str = "main";
+pub const NYX_MAX_CALL_DEPTH: usize = 1000;
+pub const NYX_STACK_SIZE: usize = 512 * 1024 * 1024;
+// Deepest recursion that stays well inside NYX_STACK_SIZE, debug builds need much larger frames.
+pub const NYX_MAX_CALL_DEPTH_LIMIT: usize = if cfg!(debug_assertions) {
+    1_500
+} else {
+    15_000
+};
+pub const NYX_MAX_FOLD_STEPS: usize = 10_000;
+// Longest string or list that folding embeds in the tree, bigger results stay as expressions.
+pub const NYX_MAX_FOLD_LENGTH: usize = 4096;
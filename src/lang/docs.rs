@@ -0,0 +1,670 @@
+use std::collections::HashMap;
+
+pub struct NyxDoc {
+    pub description: &'static str,
+    pub arity: &'static str,
+    pub arguments: &'static [&'static str],
+}
+
+impl NyxDoc {
+    const fn new(
+        description: &'static str,
+        arity: &'static str,
+        arguments: &'static [&'static str],
+    ) -> Self {
+        Self {
+            description,
+            arity,
+            arguments,
+        }
+    }
+}
+
+pub fn modules(docs: &HashMap<&'static str, NyxDoc>) -> Vec<(&'static str, Vec<&'static str>)> {
+    let mut modules: Vec<(&'static str, Vec<&'static str>)> = Vec::new();
+
+    docs.keys().for_each(|key| {
+        if let Some((module, function)) = key.split_once("::") {
+            match modules.iter_mut().find(|(name, _)| *name == module) {
+                Some((_, functions)) => functions.push(function),
+                None => modules.push((module, vec![function])),
+            }
+        }
+    });
+
+    modules.sort_by(|a, b| a.0.cmp(b.0));
+    modules
+        .iter_mut()
+        .for_each(|(_, functions)| functions.sort());
+
+    modules
+}
+
+pub fn docs() -> HashMap<&'static str, NyxDoc> {
+    HashMap::from([
+        (
+            "os::exit",
+            NyxDoc::new(
+                "Terminates the program with the given exit code.",
+                "1",
+                &["code integer"],
+            ),
+        ),
+        (
+            "os::current_time",
+            NyxDoc::new(
+                "Returns the seconds elapsed since the unix epoch, with millisecond precision.",
+                "0",
+                &[],
+            ),
+        ),
+        (
+            "os::input",
+            NyxDoc::new(
+                "Reads a line from the standard input, printing the optional prompt first.",
+                "0 or 1",
+                &["optional prompt value"],
+            ),
+        ),
+        (
+            "os::terminal_size",
+            NyxDoc::new(
+                "Returns the [columns, rows] of the terminal, or null when the output is not a terminal or its size cannot be read.",
+                "0",
+                &[],
+            ),
+        ),
+        (
+            "os::getcwd",
+            NyxDoc::new("Returns the current working directory.", "0", &[]),
+        ),
+        (
+            "os::chdir",
+            NyxDoc::new(
+                "Changes the current working directory to an existing directory.",
+                "1",
+                &["path string"],
+            ),
+        ),
+        (
+            "os::home_dir",
+            NyxDoc::new(
+                "Returns the home directory of the current user.",
+                "0",
+                &[],
+            ),
+        ),
+        (
+            "os::join_path",
+            NyxDoc::new(
+                "Joins the path components with the separator of the platform.",
+                "2 or more",
+                &["path string", "path string", "..."],
+            ),
+        ),
+        (
+            "os::sleep",
+            NyxDoc::new(
+                "Pauses the program for the given number of milliseconds.",
+                "1",
+                &["milliseconds number"],
+            ),
+        ),
+        (
+            "math::sqrt",
+            NyxDoc::new(
+                "Returns the square root of a number greater than 0.",
+                "1",
+                &["value number"],
+            ),
+        ),
+        (
+            "math::pow",
+            NyxDoc::new(
+                "Raises the base to the power of the exponent, the result must be finite.",
+                "2",
+                &["base number", "exponent number"],
+            ),
+        ),
+        (
+            "math::is_infinite",
+            NyxDoc::new(
+                "Returns true when the number is positive or negative infinity.",
+                "1",
+                &["value number"],
+            ),
+        ),
+        (
+            "math::is_nan",
+            NyxDoc::new("Returns true when the number is NaN.", "1", &["value number"]),
+        ),
+        (
+            "math::is_finite",
+            NyxDoc::new(
+                "Returns true when the number is neither infinite nor NaN.",
+                "1",
+                &["value number"],
+            ),
+        ),
+        (
+            "math::approx_eq",
+            NyxDoc::new(
+                "Returns true when both numbers differ by no more than the epsilon.",
+                "2 or 3",
+                &["left number", "right number", "optional epsilon number"],
+            ),
+        ),
+        (
+            "math::round_half_even",
+            NyxDoc::new(
+                "Rounds a number to the nearest integer, ties go to the even neighbour.",
+                "1",
+                &["value number"],
+            ),
+        ),
+        (
+            "math::mod_pow",
+            NyxDoc::new(
+                "Returns (base ^ exponent) % modulus computed over safe integers.",
+                "3",
+                &["base integer", "exponent integer", "modulus integer"],
+            ),
+        ),
+        (
+            "list::gen",
+            NyxDoc::new("Returns a new empty list.", "0", &[]),
+        ),
+        (
+            "list::add",
+            NyxDoc::new(
+                "Returns a copy of the list with every other argument appended.",
+                "2 or more",
+                &["source list", "values..."],
+            ),
+        ),
+        (
+            "list::size",
+            NyxDoc::new("Returns the number of elements of the list.", "1", &["source list"]),
+        ),
+        (
+            "list::reverse",
+            NyxDoc::new("Returns a reversed copy of the list.", "1", &["source list"]),
+        ),
+        (
+            "list::get",
+            NyxDoc::new(
+                "Returns the element stored at the index.",
+                "2",
+                &["source list", "index integer"],
+            ),
+        ),
+        (
+            "list::pop",
+            NyxDoc::new(
+                "Returns a copy of the list without its last element.",
+                "1",
+                &["source list"],
+            ),
+        ),
+        (
+            "list::remove",
+            NyxDoc::new(
+                "Returns the element stored at the index, the list itself is left untouched.",
+                "2",
+                &["source list", "index integer"],
+            ),
+        ),
+        (
+            "list::windows",
+            NyxDoc::new(
+                "Returns every contiguous sub list of the given size.",
+                "2",
+                &["source list", "size integer"],
+            ),
+        ),
+        (
+            "list::map",
+            NyxDoc::new(
+                "Returns a new list with the mapper applied to every element.",
+                "2",
+                &["source list", "mapper callable"],
+            ),
+        ),
+        (
+            "list::filter",
+            NyxDoc::new(
+                "Returns the elements for which the predicate is truthy.",
+                "2",
+                &["source list", "predicate callable"],
+            ),
+        ),
+        (
+            "list::reduce",
+            NyxDoc::new(
+                "Folds the list into a single value, starting from the initial value.",
+                "3",
+                &["source list", "reducer callable", "initial value"],
+            ),
+        ),
+        (
+            "list::scan",
+            NyxDoc::new(
+                "Folds the list like reduce, returning every intermediate value.",
+                "3",
+                &["source list", "initial value", "reducer callable"],
+            ),
+        ),
+        (
+            "list::join_deep",
+            NyxDoc::new(
+                "Joins every value of the list and its nested lists with the separator.",
+                "2",
+                &["source list", "separator string"],
+            ),
+        ),
+        (
+            "list::sort",
+            NyxDoc::new(
+                "Returns a sorted copy of a list of numbers or a list of strings.",
+                "1",
+                &["source list"],
+            ),
+        ),
+        (
+            "list::join",
+            NyxDoc::new(
+                "Joins the values of the list with the separator.",
+                "2",
+                &["source list", "separator string"],
+            ),
+        ),
+        (
+            "list::frequencies",
+            NyxDoc::new(
+                "Returns a [value, count] list for every distinct value of the list.",
+                "1",
+                &["source list"],
+            ),
+        ),
+        (
+            "list::insert",
+            NyxDoc::new(
+                "Returns a copy of the list with the value inserted at the index.",
+                "3",
+                &["source list", "index integer", "value"],
+            ),
+        ),
+        (
+            "list::range",
+            NyxDoc::new(
+                "Returns the numbers from the start up to the end (excluded) by the step.",
+                "2 or 3",
+                &["start number", "end number", "optional step number"],
+            ),
+        ),
+        (
+            "list::max_index",
+            NyxDoc::new(
+                "Returns the index of the greatest number of a non empty list.",
+                "1",
+                &["source list"],
+            ),
+        ),
+        (
+            "list::min_index",
+            NyxDoc::new(
+                "Returns the index of the smallest number of a non empty list.",
+                "1",
+                &["source list"],
+            ),
+        ),
+        (
+            "list::flatten",
+            NyxDoc::new(
+                "Returns the list with one level of nested lists spread into it.",
+                "1",
+                &["source list"],
+            ),
+        ),
+        (
+            "list::concat",
+            NyxDoc::new(
+                "Returns a new list with the elements of both lists.",
+                "2",
+                &["first list", "second list"],
+            ),
+        ),
+        (
+            "utils::type",
+            NyxDoc::new("Returns the name of the type of the value.", "1", &["value"]),
+        ),
+        (
+            "utils::parse",
+            NyxDoc::new(
+                "Converts a string to a number or a number to a string, null when it is not possible.",
+                "1",
+                &["value string or number"],
+            ),
+        ),
+        (
+            "utils::copy_fields",
+            NyxDoc::new(
+                "Copies every field of the source instance into the target instance.",
+                "2",
+                &["target instance", "source instance"],
+            ),
+        ),
+        (
+            "utils::ensure",
+            NyxDoc::new(
+                "Calls the body and then always calls the cleanup, even when the body fails.",
+                "2",
+                &["body callable", "cleanup callable"],
+            ),
+        ),
+        (
+            "utils::sort_keys",
+            NyxDoc::new(
+                "Returns the sorted keys of a list of [key, value] lists.",
+                "1",
+                &["list of [key, value] lists"],
+            ),
+        ),
+        (
+            "utils::format_number",
+            NyxDoc::new(
+                "Formats the number with the given amount of decimals.",
+                "2",
+                &["value number", "decimals number"],
+            ),
+        ),
+        (
+            "utils::assert",
+            NyxDoc::new("Fails when the value is not truthy.", "1", &["value"]),
+        ),
+        (
+            "utils::assert_eq",
+            NyxDoc::new(
+                "Fails when both values are not equal.",
+                "2",
+                &["left value", "right value"],
+            ),
+        ),
+        (
+            "utils::freeze",
+            NyxDoc::new(
+                "Returns a deep copy of the value that shares nothing with the original.",
+                "1",
+                &["value"],
+            ),
+        ),
+        (
+            "utils::is_null",
+            NyxDoc::new("Returns true when the value is null.", "1", &["value"]),
+        ),
+        (
+            "utils::is_number",
+            NyxDoc::new("Returns true when the value is a number.", "1", &["value"]),
+        ),
+        (
+            "utils::is_string",
+            NyxDoc::new("Returns true when the value is a string.", "1", &["value"]),
+        ),
+        (
+            "utils::is_bool",
+            NyxDoc::new("Returns true when the value is a bool.", "1", &["value"]),
+        ),
+        (
+            "utils::is_list",
+            NyxDoc::new("Returns true when the value is a list.", "1", &["value"]),
+        ),
+        (
+            "utils::is_callable",
+            NyxDoc::new(
+                "Returns true when the value is a function or a clazz.",
+                "1",
+                &["value"],
+            ),
+        ),
+        (
+            "utils::to_bool",
+            NyxDoc::new(
+                "Converts the string \"true\" or \"false\" to a bool, null otherwise.",
+                "1",
+                &["source string"],
+            ),
+        ),
+        (
+            "utils::to_int",
+            NyxDoc::new(
+                "Converts a string holding a safe integer to a number, null otherwise.",
+                "1",
+                &["source string"],
+            ),
+        ),
+        (
+            "utils::fields",
+            NyxDoc::new(
+                "Returns the names of the fields of the instance, in insertion order.",
+                "1",
+                &["clazz instance"],
+            ),
+        ),
+        (
+            "color::red",
+            NyxDoc::new("Paints the string red.", "1", &["source string"]),
+        ),
+        (
+            "color::green",
+            NyxDoc::new("Paints the string green.", "1", &["source string"]),
+        ),
+        (
+            "color::yellow",
+            NyxDoc::new("Paints the string yellow.", "1", &["source string"]),
+        ),
+        (
+            "color::blue",
+            NyxDoc::new("Paints the string blue.", "1", &["source string"]),
+        ),
+        (
+            "color::bold",
+            NyxDoc::new("Makes the string bold.", "1", &["source string"]),
+        ),
+        (
+            "color::rgb",
+            NyxDoc::new(
+                "Paints the string with a true color, every channel goes from 0 to 255.",
+                "4",
+                &["source string", "red number", "green number", "blue number"],
+            ),
+        ),
+        (
+            "fs::read_file",
+            NyxDoc::new("Returns the content of the file.", "1", &["path string"]),
+        ),
+        (
+            "fs::write_file",
+            NyxDoc::new(
+                "Writes the content to the file, replacing it when it exists.",
+                "2",
+                &["path string", "content string"],
+            ),
+        ),
+        (
+            "fs::exists",
+            NyxDoc::new("Returns true when the path exists.", "1", &["path string"]),
+        ),
+        (
+            "json::stringify",
+            NyxDoc::new(
+                "Serializes the value as a JSON string, clazz instances become objects.",
+                "1",
+                &["value"],
+            ),
+        ),
+        (
+            "json::parse",
+            NyxDoc::new(
+                "Parses a JSON string into a value, objects become lists of [key, value] lists.",
+                "1",
+                &["source string"],
+            ),
+        ),
+        (
+            "time::now_millis",
+            NyxDoc::new("Returns the milliseconds elapsed since the unix epoch.", "0", &[]),
+        ),
+        (
+            "time::now_secs",
+            NyxDoc::new("Returns the seconds elapsed since the unix epoch.", "0", &[]),
+        ),
+        (
+            "time::format",
+            NyxDoc::new(
+                "Formats the epoch seconds as UTC with the %Y, %m, %d, %H, %M and %S tokens.",
+                "2",
+                &["epoch seconds number", "format string"],
+            ),
+        ),
+        (
+            "string::length",
+            NyxDoc::new("Returns the length of the string in bytes.", "1", &["source string"]),
+        ),
+        (
+            "string::split",
+            NyxDoc::new(
+                "Splits the string by every occurrence of the separator and returns the parts as a list.",
+                "2",
+                &["source string", "separator string"],
+            ),
+        ),
+        (
+            "string::find",
+            NyxDoc::new(
+                "Returns the byte index of the first occurrence of the search, null when it is missing.",
+                "2",
+                &["source string", "search string"],
+            ),
+        ),
+        (
+            "string::push",
+            NyxDoc::new(
+                "Returns the string with the other string appended.",
+                "2",
+                &["source string", "value string"],
+            ),
+        ),
+        (
+            "string::replace",
+            NyxDoc::new(
+                "Replaces every occurrence of the old string with the new string.",
+                "3",
+                &["source string", "old string", "new string"],
+            ),
+        ),
+        (
+            "string::trim",
+            NyxDoc::new(
+                "Removes the leading and trailing whitespace.",
+                "1",
+                &["source string"],
+            ),
+        ),
+        (
+            "string::trim_l",
+            NyxDoc::new("Removes the leading whitespace.", "1", &["source string"]),
+        ),
+        (
+            "string::trim_r",
+            NyxDoc::new("Removes the trailing whitespace.", "1", &["source string"]),
+        ),
+        (
+            "string::codepoints",
+            NyxDoc::new(
+                "Returns the unicode codepoint of every character as a list.",
+                "1",
+                &["source string"],
+            ),
+        ),
+        (
+            "string::contains",
+            NyxDoc::new(
+                "Returns true when the string contains the search.",
+                "2",
+                &["source string", "search string"],
+            ),
+        ),
+        (
+            "string::starts_with",
+            NyxDoc::new(
+                "Returns true when the string starts with the prefix.",
+                "2",
+                &["source string", "prefix string"],
+            ),
+        ),
+        (
+            "string::ends_with",
+            NyxDoc::new(
+                "Returns true when the string ends with the suffix.",
+                "2",
+                &["source string", "suffix string"],
+            ),
+        ),
+        (
+            "string::substring",
+            NyxDoc::new(
+                "Returns the part of the string between the start and the end (excluded).",
+                "3",
+                &["source string", "start number", "end number"],
+            ),
+        ),
+        (
+            "string::repeat",
+            NyxDoc::new(
+                "Returns the string repeated the given amount of times.",
+                "2",
+                &["source string", "count number"],
+            ),
+        ),
+        (
+            "string::visible_length",
+            NyxDoc::new(
+                "Returns the amount of characters of the string, ignoring ANSI escape codes.",
+                "1",
+                &["source string"],
+            ),
+        ),
+        (
+            "string::swap_case",
+            NyxDoc::new(
+                "Turns uppercase characters to lowercase and lowercase characters to uppercase.",
+                "1",
+                &["source string"],
+            ),
+        ),
+        (
+            "string::expand_tabs",
+            NyxDoc::new(
+                "Replaces every tab with spaces up to the next tab stop.",
+                "2",
+                &["source string", "tab width integer greater than 0"],
+            ),
+        ),
+        (
+            "string::fit",
+            NyxDoc::new(
+                "Pads or truncates the string to the width, optionally ending it with an ellipsis.",
+                "2 or 3",
+                &["source string", "width non-negative integer", "optional ellipsis bool"],
+            ),
+        ),
+        (
+            "string::char_at",
+            NyxDoc::new(
+                "Returns the character stored at the index.",
+                "2",
+                &["source string", "integer index"],
+            ),
+        ),
+    ])
+}
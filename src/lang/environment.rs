@@ -5,6 +5,7 @@ use super::{expr::LiteralValue, panic::PanicHandler};
 #[derive(Clone)]
 pub struct Environment {
     pub values: Rc<RefCell<HashMap<String, LiteralValue>>>,
+    pub constants: Rc<RefCell<HashMap<String, (usize, usize)>>>,
     pub locals: Rc<RefCell<HashMap<usize, usize>>>,
     pub enclosing: Option<Rc<Environment>>,
 }
@@ -13,6 +14,7 @@ impl Environment {
     pub fn new(locals: HashMap<usize, usize>) -> Self {
         Self {
             values: Rc::new(RefCell::new(HashMap::new())),
+            constants: Rc::new(RefCell::new(HashMap::new())),
             locals: Rc::new(RefCell::new(locals)),
             enclosing: None,
         }
@@ -24,13 +26,10 @@ impl Environment {
         });
     }
 
-    pub fn get_value(&self, name: String) -> Option<LiteralValue> {
-        self.values.borrow().get(&name).cloned()
-    }
-
     pub fn enclose(&self) -> Environment {
         Self {
             values: Rc::new(RefCell::new(HashMap::new())),
+            constants: Rc::new(RefCell::new(HashMap::new())),
             locals: self.locals.clone(),
             enclosing: Some(Rc::new(self.clone())),
         }
@@ -38,18 +37,43 @@ impl Environment {
 
     pub fn define(&self, name: &str, value: LiteralValue) {
         self.values.borrow_mut().insert(name.to_string(), value);
+        self.constants.borrow_mut().remove(name);
+    }
+
+    pub fn define_constant(&self, name: &str, value: LiteralValue, declared: (usize, usize)) {
+        self.define(name, value);
+        self.constants
+            .borrow_mut()
+            .insert(name.to_string(), declared);
     }
 
-    pub fn constant(&self, name: &str) -> bool {
-        self.values
-            .borrow()
-            .contains_key(format!("__const__{}", name).as_str())
+    pub fn constant(&self, name: &str, id: usize) -> Option<(usize, usize)> {
+        let distance: Option<usize> = self.locals.borrow().get(&id).cloned();
+
+        self.scope(distance)?.constants.borrow().get(name).cloned()
+    }
+
+    fn scope(&self, distance: Option<usize>) -> Option<&Environment> {
+        match (distance, &self.enclosing) {
+            (Some(0), _) | (None, None) => Some(self),
+            (Some(distance), Some(env)) => env.scope(Some(distance - 1)),
+            (None, Some(env)) => env.scope(None),
+            (Some(_), None) => None,
+        }
     }
 
     pub fn get(&self, name: &str, id: usize) -> Option<LiteralValue> {
         self.internal(name, self.locals.borrow().get(&id).cloned())
     }
 
+    pub fn names(&self) -> Vec<String> {
+        self.values.borrow().keys().cloned().collect()
+    }
+
+    pub fn get_global(&self, name: &str) -> Option<LiteralValue> {
+        self.internal(name, None)
+    }
+
     pub fn get_this_instance(&self, id: usize) -> Option<LiteralValue> {
         let distance: usize = self.locals.borrow().get(&id).cloned().unwrap_or_else(|| {
             PanicHandler::new(
@@ -67,21 +91,7 @@ impl Environment {
     }
 
     fn internal(&self, name: &str, distance: Option<usize>) -> Option<LiteralValue> {
-        if distance.is_none() {
-            match &self.enclosing {
-                None => {
-                    let const_i: String = format!("__const__{}", name);
-
-                    if !self.values.borrow().contains_key(const_i.as_str()) {
-                        return self.values.borrow().get(name).cloned();
-                    }
-
-                    self.values.borrow().get(const_i.as_str()).cloned()
-                }
-                Some(env) => env.internal(name, distance),
-            }
-        } else {
-            let distance: usize = distance.unwrap();
+        if let Some(distance) = distance {
             if distance == 0 {
                 self.values.borrow().get(name).cloned()
             } else {
@@ -103,6 +113,11 @@ impl Environment {
                     Some(env) => env.internal(name, Some(distance - 1)),
                 }
             }
+        } else {
+            match &self.enclosing {
+                None => self.values.borrow().get(name).cloned(),
+                Some(env) => env.internal(name, distance),
+            }
         }
     }
 
@@ -115,17 +130,8 @@ impl Environment {
     }
 
     fn assign_internal(&self, name: &str, value: &LiteralValue, distance: Option<usize>) -> bool {
-        if distance.is_none() {
-            match &self.enclosing {
-                Some(env) => env.assign_internal(name, value, distance),
-                None => self
-                    .values
-                    .borrow_mut()
-                    .insert(name.to_string(), value.to_owned())
-                    .is_some(),
-            }
-        } else {
-            if distance.unwrap() == 0 {
+        if let Some(distance) = distance {
+            if distance == 0 {
                 self.values
                     .borrow_mut()
                     .insert(name.to_string(), value.to_owned());
@@ -140,8 +146,7 @@ impl Environment {
                         None,
                         format!(
                             "Could not find variable ({}) at distance ({}).",
-                            name,
-                            distance.unwrap()
+                            name, distance
                         )
                         .as_str(),
                     )
@@ -149,9 +154,18 @@ impl Environment {
 
                     false
                 }
-                Some(env) => env.assign_internal(name, value, Some(distance.unwrap() - 1)),
+                Some(env) => env.assign_internal(name, value, Some(distance - 1)),
             };
             true
+        } else {
+            match &self.enclosing {
+                Some(env) => env.assign_internal(name, value, distance),
+                None => self
+                    .values
+                    .borrow_mut()
+                    .insert(name.to_string(), value.to_owned())
+                    .is_some(),
+            }
         }
     }
 }
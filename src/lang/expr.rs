@@ -1,6 +1,12 @@
-use std::{cell::RefCell, cmp::PartialEq, collections::HashMap, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    cmp::PartialEq,
+    collections::HashMap,
+    rc::Rc,
+};
 
 use super::{
+    constants::{NYX_MAX_CALL_DEPTH, NYX_MAX_CALL_DEPTH_LIMIT, NYX_MAX_STRING_LENGTH},
     environment::Environment,
     interpreter::NyxInterpreter,
     panic::PanicHandler,
@@ -56,40 +62,152 @@ pub enum LiteralValue {
     List(Vec<LiteralValue>),
 }
 
+thread_local! {
+    static MAX_CALL_DEPTH: Cell<usize> = const { Cell::new(NYX_MAX_CALL_DEPTH) };
+    static CALL_DEPTH: Cell<usize> = const { Cell::new(0) };
+    static STEPS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+pub fn set_max_depth(max_depth: usize) {
+    MAX_CALL_DEPTH.with(|depth| depth.set(max_depth.min(NYX_MAX_CALL_DEPTH_LIMIT)));
+}
+
+pub fn step() -> Result<(), String> {
+    allocate(1)
+}
+
+/// Charges `size` steps at once, natives call it before building large strings or
+/// lists so a bounded evaluation also bounds what it allocates.
+pub fn allocate(size: usize) -> Result<(), String> {
+    STEPS.with(|steps| match steps.get() {
+        None => Ok(()),
+        Some(left) if left < size => Err("The evaluation ran out of steps.".to_string()),
+        Some(left) => {
+            steps.set(Some(left - size));
+            Ok(())
+        }
+    })
+}
+
+fn charge(value: LiteralValue) -> Result<LiteralValue, String> {
+    match &value {
+        LiteralValue::StringValue(s) => allocate(s.len())?,
+        LiteralValue::List(list) => allocate(list.len())?,
+        _ => {}
+    }
+
+    Ok(value)
+}
+
+pub fn with_steps<T>(steps: usize, run: impl FnOnce() -> T) -> T {
+    let previous: Option<usize> = STEPS.with(|cell| cell.replace(Some(steps)));
+    let _steps: StepsGuard = StepsGuard(previous);
+
+    run()
+}
+
+struct StepsGuard(Option<usize>);
+
+impl Drop for StepsGuard {
+    fn drop(&mut self) {
+        STEPS.with(|steps| steps.set(self.0));
+    }
+}
+
 pub fn run_function(
     fc: FunctionImpl,
     args: &[Expr],
+    named: &[(Token, Expr)],
     eval_env: &Environment,
 ) -> Result<LiteralValue, String> {
-    if args.len() as u8 != fc.arity {
-        PanicHandler::new(
-            None,
-            None,
-            None,
-            format!(
-                "Callable ({}) expected ({}) arguments but got ({}) instead.",
-                fc.name,
-                fc.arity,
-                args.len()
-            )
-            .as_str(),
-        )
-        .panic();
+    let mut parsed_args: Vec<LiteralValue> = Vec::with_capacity(args.len());
 
-        return Ok(LiteralValue::Null);
+    for arg in args {
+        parsed_args.push(arg.evaluate(eval_env)?);
     }
 
-    let fc_env: Environment = fc.parent_env.enclose();
+    if named.is_empty() || parsed_args.len() > fc.params.len() {
+        return call_function(fc, &parsed_args);
+    }
 
-    let mut parsed_args: Vec<LiteralValue> = Vec::with_capacity(args.len());
+    let mut slots: Vec<Option<LiteralValue>> = parsed_args.into_iter().map(Some).collect();
+    slots.resize(fc.params.len(), None);
+
+    for (name, arg) in named {
+        let Some(position) = fc.params.iter().position(|p| p.lexeme == name.lexeme) else {
+            return Err(format!(
+                "Callable ({}) has no parameter named ({}). ({}:{})",
+                fc.name, name.lexeme, name.line, name.column
+            ));
+        };
+
+        if slots[position].is_some() {
+            return Err(format!(
+                "The parameter ({}) of ({}) was given more than once. ({}:{})",
+                name.lexeme, fc.name, name.line, name.column
+            ));
+        }
 
-    for arg in args {
-        if let Ok(literal) = arg.evaluate(eval_env) {
-            parsed_args.push(literal);
+        slots[position] = Some(arg.evaluate(eval_env)?);
+    }
+
+    let mut values: Vec<LiteralValue> = Vec::with_capacity(slots.len());
+
+    for (param, slot) in fc.params.iter().zip(slots) {
+        match slot {
+            Some(value) => values.push(value),
+            None => {
+                return Err(format!(
+                    "Callable ({}) is missing the argument ({}).",
+                    fc.name, param.lexeme
+                ))
+            }
         }
     }
 
-    parsed_args.iter().enumerate().for_each(|(i, val)| {
+    call_function(fc, &values)
+}
+
+pub fn call_function(fc: FunctionImpl, args: &[LiteralValue]) -> Result<LiteralValue, String> {
+    if args.len() as u8 != fc.arity {
+        return Err(format!(
+            "Callable ({}) expected ({}) arguments but got ({}) instead.",
+            fc.name,
+            fc.arity,
+            args.len()
+        ));
+    }
+
+    let max_depth: usize = MAX_CALL_DEPTH.with(|depth| depth.get());
+    let depth: usize = CALL_DEPTH.with(|depth| depth.get());
+
+    if depth >= max_depth {
+        return Err(format!(
+            "Maximum recursion depth ({}) exceeded while calling ({}).",
+            max_depth, fc.name
+        ));
+    }
+
+    step()?;
+
+    CALL_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let _depth: CallDepthGuard = CallDepthGuard;
+
+    execute_function(&fc, args)
+}
+
+struct CallDepthGuard;
+
+impl Drop for CallDepthGuard {
+    fn drop(&mut self) {
+        CALL_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+fn execute_function(fc: &FunctionImpl, args: &[LiteralValue]) -> Result<LiteralValue, String> {
+    let fc_env: Environment = fc.parent_env.enclose();
+
+    args.iter().enumerate().for_each(|(i, val)| {
         fc_env.define(&fc.params[i].lexeme, val.clone());
     });
 
@@ -106,6 +224,15 @@ pub fn run_function(
     Ok(LiteralValue::Null)
 }
 
+impl CallableImpl {
+    pub fn call(&self, args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        match self {
+            CallableImpl::Function(fc) => call_function(fc.to_owned(), args),
+            CallableImpl::NativeFunction(nativefc) => (nativefc.fc)(args).and_then(charge),
+        }
+    }
+}
+
 pub fn find_method(name: &str, class: LiteralValue) -> Option<FunctionImpl> {
     if let LiteralValue::Clazz {
         name: _,
@@ -126,7 +253,74 @@ pub fn find_method(name: &str, class: LiteralValue) -> Option<FunctionImpl> {
     unreachable!()
 }
 
+fn get_property(obj_value: LiteralValue, name: &Token) -> Result<LiteralValue, String> {
+    if let LiteralValue::ClassInstance { class, fields } = obj_value.clone() {
+        for (field_name, value) in (*fields.borrow()).iter() {
+            if *field_name == name.lexeme {
+                return Ok(value.to_owned());
+            }
+        }
+
+        if let LiteralValue::Clazz {
+            name: _,
+            methods: _,
+            superclass: _,
+        } = *class
+        {
+            if let Some(method) = find_method(&name.lexeme, (*class).clone()) {
+                let mut callable_impl: FunctionImpl = method;
+
+                let new_env = callable_impl.parent_env.enclose();
+
+                new_env.define("this", obj_value);
+
+                callable_impl.parent_env = new_env;
+
+                return Ok(LiteralValue::Callable(CallableImpl::Function(
+                    callable_impl,
+                )));
+            }
+        }
+
+        return Err(PanicHandler::new(
+            Some(name.line),
+            Some(name.column),
+            Some(&name.lexeme),
+            "The clazz field on an instance was not a clazz.",
+        )
+        .format());
+    }
+    Err(PanicHandler::new(
+        Some(name.line),
+        Some(name.column),
+        Some(&name.lexeme),
+        "The object does not contain this property.",
+    )
+    .format())
+}
+
 impl LiteralValue {
+    pub fn display(&self) -> Result<String, String> {
+        match self.method("str") {
+            Some(method) => Ok(call_function(method, &[])?.convert()),
+            None => Ok(self.convert()),
+        }
+    }
+
+    fn method(&self, name: &str) -> Option<FunctionImpl> {
+        let LiteralValue::ClassInstance { class, fields: _ } = self else {
+            return None;
+        };
+
+        let mut method: FunctionImpl = find_method(name, (**class).clone())?;
+
+        let new_env: Environment = method.parent_env.enclose();
+        new_env.define("this", self.to_owned());
+        method.parent_env = new_env;
+
+        Some(method)
+    }
+
     pub fn convert(&self) -> String {
         match self {
             LiteralValue::Number(x) => x.to_string(),
@@ -248,96 +442,29 @@ impl LiteralValue {
         LiteralValue::False
     }
 
-    fn is_false(&self) -> LiteralValue {
-        match self {
-            LiteralValue::Number(x) => {
-                if *x == 0.0_f64 {
-                    return LiteralValue::True;
-                }
-
-                LiteralValue::False
-            }
-            LiteralValue::StringValue(s) => {
-                if s.is_empty() {
-                    return LiteralValue::True;
-                }
-
-                LiteralValue::False
-            }
-            LiteralValue::True => LiteralValue::False,
-            LiteralValue::False => LiteralValue::True,
-            LiteralValue::Null => LiteralValue::True,
-            LiteralValue::Callable(_) => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "A Callable should not be used as a boolean value.",
-                )
-                .panic();
-                unreachable!()
-            }
-            LiteralValue::Clazz { .. } => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "A Clazz should not be used as a boolean value.",
-                )
-                .panic();
-                unreachable!()
-            }
-            _ => {
-                PanicHandler::new(None, None, None, "Object is not valid as a boolean value.")
-                    .panic();
-                unreachable!()
-            }
+    fn is_false(&self) -> Result<LiteralValue, String> {
+        match self.truthy()? {
+            LiteralValue::True => Ok(LiteralValue::False),
+            _ => Ok(LiteralValue::True),
         }
     }
 
-    pub fn truthy(&self) -> LiteralValue {
+    pub fn truthy(&self) -> Result<LiteralValue, String> {
         match self {
-            LiteralValue::Number(x) => {
-                if *x == 0.0_f64 {
-                    return LiteralValue::False;
-                }
-
-                LiteralValue::True
-            }
-            LiteralValue::StringValue(s) => {
-                if s.is_empty() {
-                    return LiteralValue::False;
-                }
-
-                LiteralValue::True
-            }
-            LiteralValue::True => LiteralValue::True,
-            LiteralValue::False => LiteralValue::False,
-            LiteralValue::Null => LiteralValue::False,
+            LiteralValue::Number(x) => Ok(LiteralValue::bool(*x != 0.0_f64)),
+            LiteralValue::StringValue(s) => Ok(LiteralValue::bool(!s.is_empty())),
+            LiteralValue::True => Ok(LiteralValue::True),
+            LiteralValue::False => Ok(LiteralValue::False),
+            LiteralValue::Null => Ok(LiteralValue::False),
             LiteralValue::Callable(_) => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "A Callable should not be used as a boolean value.",
-                )
-                .panic();
-                unreachable!()
+                Err("A Callable should not be used as a boolean value.".to_string())
             }
             LiteralValue::Clazz { .. } => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "A Clazz should not be used as a boolean value.",
-                )
-                .panic();
-                unreachable!()
+                Err("A Clazz should not be used as a boolean value.".to_string())
             }
-            _ => {
-                PanicHandler::new(None, None, None, "Object is not valid as a boolean value.")
-                    .panic();
-                unreachable!()
+            LiteralValue::List(list) => Ok(LiteralValue::bool(!list.is_empty())),
+            LiteralValue::ClassInstance { .. } | LiteralValue::Module { .. } => {
+                Ok(LiteralValue::True)
             }
         }
     }
@@ -368,20 +495,36 @@ pub enum Expr {
         call: Rc<Expr>,
         paren: Token,
         arguments: Vec<Expr>,
+        named: Vec<(Token, Expr)>,
     },
     Get {
         id: usize,
         object: Rc<Expr>,
         name: Token,
     },
+    OptionalGet {
+        id: usize,
+        object: Rc<Expr>,
+        name: Token,
+    },
     Grouping {
         id: usize,
         expression: Rc<Expr>,
     },
-    Literal {
+    Index {
         id: usize,
+        object: Rc<Expr>,
+        bracket: Token,
+        index: Rc<Expr>,
+    },
+    Literal {
         value: LiteralValue,
     },
+    List {
+        id: usize,
+        bracket: Token,
+        elements: Vec<Expr>,
+    },
     Logical {
         id: usize,
         left: Rc<Expr>,
@@ -408,6 +551,12 @@ pub enum Expr {
         operator: Token,
         right: Rc<Expr>,
     },
+    Update {
+        id: usize,
+        operator: Token,
+        target: Rc<Expr>,
+        prefix: bool,
+    },
     Variable {
         id: usize,
         name: Token,
@@ -430,7 +579,9 @@ impl Expr {
                 arguments,
                 body: _,
             } => format!("anon/{}", arguments.len()),
-            Expr::Assign { id: _, name, value } => format!("({name:?} = {}", value.convert()),
+            Expr::Assign { id: _, name, value } => {
+                format!("(= {} {})", name.lexeme, value.convert())
+            }
             Expr::Binary {
                 id: _,
                 left,
@@ -445,19 +596,58 @@ impl Expr {
             Expr::Call {
                 id: _,
                 call,
-                module: _,
+                module,
                 paren: _,
-                arguments: _,
-            } => format!("({})", call.convert()),
+                arguments,
+                named,
+            } => format!(
+                "(call {}{}{}{})",
+                module
+                    .as_ref()
+                    .map(|m| format!("{m}::"))
+                    .unwrap_or_default(),
+                call.convert(),
+                arguments
+                    .iter()
+                    .map(|a| format!(" {}", a.convert()))
+                    .collect::<String>(),
+                named
+                    .iter()
+                    .map(|(name, a)| format!(" {}: {}", name.lexeme, a.convert()))
+                    .collect::<String>()
+            ),
             Expr::Get {
                 id: _,
                 object,
                 name,
             } => format!("(get {} {})", object.convert(), name.lexeme),
+            Expr::OptionalGet {
+                id: _,
+                object,
+                name,
+            } => format!("(get? {} {})", object.convert(), name.lexeme),
             Expr::Grouping { id: _, expression } => {
                 format!("(group {})", expression.convert())
             }
-            Expr::Literal { id: _, value } => value.convert(),
+            Expr::Index {
+                id: _,
+                object,
+                bracket: _,
+                index,
+            } => format!("(index {} {})", object.convert(), index.convert()),
+            Expr::Literal { value } => value.convert(),
+            Expr::List {
+                id: _,
+                bracket: _,
+                elements,
+            } => format!(
+                "(list {})",
+                elements
+                    .iter()
+                    .map(|e| e.convert())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
             Expr::Logical {
                 id: _,
                 left,
@@ -496,6 +686,17 @@ impl Expr {
             } => {
                 format!("({} {})", operator.lexeme.to_owned(), right.convert())
             }
+            Expr::Update {
+                id: _,
+                operator,
+                target,
+                prefix,
+            } => format!(
+                "({}{} {})",
+                if *prefix { "prefix " } else { "postfix " },
+                operator.lexeme,
+                target.convert()
+            ),
             Expr::Variable { id: _, name } => format!("(let {})", name.lexeme),
 
             Expr::ModuleProperty {
@@ -525,42 +726,86 @@ impl Expr {
             Expr::Assign { id, name, value } => {
                 let new: LiteralValue = value.evaluate(environment)?;
 
-                if environment.constant(name.lexeme.as_str()) {
-                    PanicHandler::new(
-                        Some(name.line),
-                        Some(name.column),
-                        Some(&name.lexeme),
-                        "A constant is not allowed to be reassigned.",
-                    )
-                    .panic();
+                if let Some((line, column)) = environment.constant(name.lexeme.as_str(), *id) {
+                    return Err(format!(
+                        "The constant ({}) declared at ({}:{}) is not allowed to be reassigned. ({}:{})",
+                        name.lexeme, line, column, name.line, name.column
+                    ));
                 } else if environment.assign(name.lexeme.as_str(), &new, *id) {
                     return Ok(new);
                 }
 
-                PanicHandler::new(
+                Err(PanicHandler::new(
                     Some(name.line),
                     Some(name.column),
                     Some(&name.lexeme),
                     "The variable has not been declared.",
                 )
-                .panic();
-
-                Ok(LiteralValue::Null)
+                .format())
             }
 
-            Expr::Variable { id, name } => match environment.get(name.lexeme.as_str(), *id) {
-                Some(value) => Ok(value),
-                None => {
-                    PanicHandler::new(
-                        Some(name.line),
-                        Some(name.column),
-                        Some(&name.lexeme),
-                        "A Variable || Callable || Clazz || Module has not been declared.",
-                    )
-                    .panic();
+            Expr::Update {
+                id: _,
+                operator,
+                target,
+                prefix,
+            } => {
+                let target: Expr = match target.as_ref() {
+                    Expr::Get { id, object, name } => Expr::Get {
+                        id: *id,
+                        object: Rc::new(Expr::Literal {
+                            value: object.evaluate(environment)?,
+                        }),
+                        name: name.clone(),
+                    },
+                    target => target.clone(),
+                };
+
+                let old: f64 = match target.evaluate(environment)? {
+                    LiteralValue::Number(n) => n,
+                    value => {
+                        return Err(format!(
+                            "The operator ({}) cannot be applied to a value of type ({}). ({}:{})",
+                            operator.lexeme,
+                            value.to_type(),
+                            operator.line,
+                            operator.column
+                        ))
+                    }
+                };
 
-                    Ok(LiteralValue::Null)
+                let new: f64 = match operator.token_type {
+                    TokenType::PlusPlus => old + 1.0,
+                    _ => old - 1.0,
+                };
+
+                let value: Rc<Expr> = Rc::new(Expr::Literal {
+                    value: LiteralValue::Number(new),
+                });
+
+                match target {
+                    Expr::Variable { id, name } => Expr::Assign { id, name, value },
+                    Expr::Get { id, object, name } => Expr::Set {
+                        id,
+                        object,
+                        name,
+                        value,
+                    },
+                    _ => unreachable!(),
                 }
+                .evaluate(environment)?;
+
+                Ok(LiteralValue::Number(if *prefix { new } else { old }))
+            }
+            Expr::Variable { id, name } => match environment.get(name.lexeme.as_str(), *id) {
+                Some(value) => Ok(value),
+                None => Err(PanicHandler::new(
+                    Some(name.line),
+                    Some(name.column),
+                    Some(&name.lexeme),
+                    "A Variable || Callable || Clazz || Module has not been declared.",
+                )
+                .format()),
             },
 
             Expr::ModuleProperty { id, module, name } => {
@@ -577,34 +822,33 @@ impl Expr {
                                 }
                             }
 
-                            PanicHandler::new(
+                            return Err(PanicHandler::new(
                                 Some(name.line),
                                 Some(name.column),
                                 Some(module),
                                 "Unknown constant in standard library module.",
                             )
-                            .panic();
+                            .format());
                         }
                         _ => {
-                            PanicHandler::new(
+                            return Err(PanicHandler::new(
                                 Some(name.line),
                                 Some(name.column),
                                 Some(module),
                                 "Unknown module in standard library.",
                             )
-                            .panic();
+                            .format());
                         }
                     }
                 }
 
-                PanicHandler::new(
+                Err(PanicHandler::new(
                     Some(name.line),
                     Some(name.column),
                     Some(module),
                     "Unknown module in standard library.",
                 )
-                .panic();
-                unreachable!()
+                .format())
             }
 
             Expr::Call {
@@ -613,79 +857,68 @@ impl Expr {
                 module,
                 paren,
                 arguments,
+                named,
             } => {
                 let callable: LiteralValue = call.evaluate(environment)?;
 
+                if let Some((name, _)) = named.first() {
+                    if module.is_some()
+                        || matches!(
+                            callable,
+                            LiteralValue::Callable(CallableImpl::NativeFunction(_))
+                        )
+                    {
+                        return Err(format!(
+                            "Functions of the standard library do not accept named arguments like ({}). ({}:{})",
+                            name.lexeme, name.line, name.column
+                        ));
+                    }
+                }
+
                 match module {
                     Some(module) => match callable {
-                        LiteralValue::StringValue(s) => {
-                            if let Some(md) = environment.get(module, *id) {
-                                match md {
-                                    LiteralValue::Module {
-                                        name: _, methods, ..
-                                    } => {
-                                        if let Some(nativefc) = methods.get(s.as_str()) {
-                                            let mut eval_args: Vec<LiteralValue> = Vec::new();
-
-                                            arguments.iter().try_for_each(|arg| {
-                                                match arg.evaluate(environment) {
-                                                    Ok(v) => {
-                                                        eval_args.push(v);
-                                                        Ok(())
-                                                    }
-                                                    Err(any) => Err(any),
-                                                }
-                                            })?;
-
-                                            return Ok((nativefc.fc)(&eval_args));
+                        LiteralValue::StringValue(s) => match environment.get(module, *id) {
+                            Some(LiteralValue::Module {
+                                name: _, methods, ..
+                            }) => {
+                                if let Some(nativefc) = methods.get(s.as_str()) {
+                                    let mut eval_args: Vec<LiteralValue> = Vec::new();
+
+                                    arguments.iter().try_for_each(|arg| {
+                                        match arg.evaluate(environment) {
+                                            Ok(v) => {
+                                                eval_args.push(v);
+                                                Ok(())
+                                            }
+                                            Err(any) => Err(any),
                                         }
+                                    })?;
 
-                                        PanicHandler::new(
-                                            Some(paren.line),
-                                            Some(paren.column),
-                                            Some(&s),
-                                            "Unknown method of a module of the standard library.",
-                                        )
-                                        .panic();
-                                    }
-
-                                    _ => {
-                                        PanicHandler::new(
-                                            Some(paren.line),
-                                            Some(paren.column),
-                                            Some(&s),
-                                            "Unknown module in standard library.",
-                                        )
-                                        .panic();
-                                    }
+                                    return (nativefc.fc)(&eval_args).and_then(charge);
                                 }
-                            }
 
-                            PanicHandler::new(
-                                Some(paren.line),
-                                Some(paren.column),
-                                Some(&s),
-                                "Unknown module in standard library.",
-                            )
-                            .panic();
-                            unreachable!()
-                        }
-
-                        _ => {
-                            PanicHandler::new(
-                                Some(paren.line),
-                                Some(paren.column),
-                                Some(&callable.convert()),
-                                "Any Object is not callable.",
-                            )
-                            .panic();
-                            unreachable!()
-                        }
+                                Err(format!(
+                                    "Unknown method ({}) of a module of the standard library. ({}:{})",
+                                    s, paren.line, paren.column
+                                ))
+                            }
+                            _ => Err(format!(
+                                "Unknown module ({}) in standard library. ({}:{})",
+                                module, paren.line, paren.column
+                            )),
+                        },
+
+                        _ => Err(format!(
+                            "({}) is not callable. ({}:{})",
+                            callable.convert(),
+                            paren.line,
+                            paren.column
+                        )),
                     },
 
                     None => match callable.clone() {
                         LiteralValue::Callable(CallableImpl::Function(fc)) => {
-                            run_function(fc, arguments, environment)
+                            run_function(fc, arguments, named, environment)
                         }
                         LiteralValue::Callable(CallableImpl::NativeFunction(nativefc)) => {
                             let mut eval_args: Vec<LiteralValue> = Vec::new();
@@ -700,7 +933,7 @@ impl Expr {
                                 }
                             })?;
 
-                            Ok((nativefc.fc)(&eval_args))
+                            (nativefc.fc)(&eval_args).and_then(charge)
                         }
                         LiteralValue::Clazz { name, methods, .. } => {
                             let instance: LiteralValue = LiteralValue::ClassInstance {
@@ -709,14 +942,15 @@ impl Expr {
                             };
 
                             if let Some(init_method) = methods.get("init") {
-                                if init_method.arity != arguments.len() as u8 {
-                                    PanicHandler::new(
-                                        Some(paren.line),
-                                        Some(paren.column),
-                                        Some(&name),
-                                        "The clazz expected more arguments.",
-                                    )
-                                    .panic();
+                                if init_method.arity != (arguments.len() + named.len()) as u8 {
+                                    return Err(format!(
+                                        "Clazz ({}) expected ({}) arguments but got ({}) instead. ({}:{})",
+                                        name,
+                                        init_method.arity,
+                                        arguments.len() + named.len(),
+                                        paren.line,
+                                        paren.column
+                                    ));
                                 }
 
                                 let mut init: FunctionImpl = init_method.to_owned();
@@ -724,25 +958,34 @@ impl Expr {
                                 init.parent_env = init_method.parent_env.enclose();
                                 init.parent_env.define("this", instance.clone());
 
-                                run_function(init, arguments, environment)?;
+                                run_function(init, arguments, named, environment)?;
                             }
 
                             Ok(instance)
                         }
-                        _ => {
-                            PanicHandler::new(
-                                Some(paren.line),
-                                Some(paren.column),
-                                Some(&callable.convert()),
-                                "Any Object is not callable.",
-                            )
-                            .panic();
-                            unreachable!()
-                        }
+                        _ => Err(format!(
+                            "({}) is not callable. ({}:{})",
+                            callable.convert(),
+                            paren.line,
+                            paren.column
+                        )),
                     },
                 }
             }
-            Expr::Literal { id: _, value } => Ok(value.to_owned()),
+            Expr::Literal { value } => Ok(value.to_owned()),
+            Expr::List {
+                id: _,
+                bracket: _,
+                elements,
+            } => {
+                let mut list: Vec<LiteralValue> = Vec::with_capacity(elements.len());
+
+                for element in elements {
+                    list.push(element.evaluate(environment)?);
+                }
+
+                Ok(LiteralValue::List(list))
+            }
             Expr::Logical {
                 id: _,
                 left,
@@ -751,7 +994,7 @@ impl Expr {
             } => match operator.token_type {
                 TokenType::Or => {
                     let lhs: LiteralValue = left.evaluate(environment)?;
-                    if lhs.truthy() == LiteralValue::True {
+                    if lhs.truthy()? == LiteralValue::True {
                         return Ok(lhs);
                     }
 
@@ -759,76 +1002,33 @@ impl Expr {
                 }
                 TokenType::And => {
                     let lhs: LiteralValue = left.evaluate(environment)?;
-                    if lhs.truthy() == LiteralValue::False {
-                        return Ok(lhs.truthy());
+                    if lhs.truthy()? == LiteralValue::False {
+                        return Ok(lhs);
                     }
 
                     right.evaluate(environment)
                 }
-                _ => {
-                    PanicHandler::new(
-                        Some(operator.line),
-                        Some(operator.column),
-                        Some(&operator.lexeme),
-                        "Uknown logical operator.",
-                    )
-                    .panic();
-                    unreachable!()
-                }
+                _ => Err(PanicHandler::new(
+                    Some(operator.line),
+                    Some(operator.column),
+                    Some(&operator.lexeme),
+                    "Uknown logical operator.",
+                )
+                .format()),
             },
             Expr::Get {
                 id: _,
                 object,
                 name,
-            } => {
-                let obj_value: LiteralValue = object.evaluate(environment)?;
-
-                if let LiteralValue::ClassInstance { class, fields } = obj_value.clone() {
-                    for (field_name, value) in (*fields.borrow()).iter() {
-                        if *field_name == name.lexeme {
-                            return Ok(value.to_owned());
-                        }
-                    }
-
-                    if let LiteralValue::Clazz {
-                        name: _,
-                        methods: _,
-                        superclass: _,
-                    } = *class
-                    {
-                        if let Some(method) = find_method(&name.lexeme, (*class).clone()) {
-                            let mut callable_impl: FunctionImpl = method;
-
-                            let new_env = callable_impl.parent_env.enclose();
-
-                            new_env.define("this", obj_value);
-
-                            callable_impl.parent_env = new_env;
-
-                            return Ok(LiteralValue::Callable(CallableImpl::Function(
-                                callable_impl,
-                            )));
-                        }
-                    }
-
-                    PanicHandler::new(
-                        Some(name.line),
-                        Some(name.column),
-                        Some(&name.lexeme),
-                        "The clazz field on an instance was not a clazz.",
-                    )
-                    .panic();
-                }
-                PanicHandler::new(
-                    Some(name.line),
-                    Some(name.column),
-                    Some(&name.lexeme),
-                    "The object does not contain this property.",
-                )
-                .panic();
-
-                Ok(LiteralValue::Null)
-            }
+            } => get_property(object.evaluate(environment)?, name),
+            Expr::OptionalGet {
+                id: _,
+                object,
+                name,
+            } => match object.evaluate(environment)? {
+                LiteralValue::Null => Ok(LiteralValue::Null),
+                obj_value => get_property(obj_value, name),
+            },
             Expr::Set {
                 id: _,
                 object,
@@ -860,46 +1060,37 @@ impl Expr {
                     return Ok(LiteralValue::Null);
                 }
 
-                PanicHandler::new(
+                Err(PanicHandler::new(
                     Some(name.line),
                     Some(name.column),
                     Some(&name.lexeme),
                     "The object does not contain this property.",
                 )
-                .panic();
-
-                Ok(LiteralValue::Null)
-            }
-            Expr::This { id, keyword } => {
-                let this: LiteralValue = environment.get("this", *id).unwrap_or_else(|| {
-                    PanicHandler::new(
-                        Some(keyword.line),
-                        Some(keyword.column),
-                        Some(&keyword.lexeme),
-                        "Couldn't lookup 'super'.",
-                    )
-                    .panic();
-
-                    LiteralValue::Null
-                });
-                Ok(this)
+                .format())
             }
+            Expr::This { id, keyword } => environment.get("this", *id).ok_or_else(|| {
+                PanicHandler::new(
+                    Some(keyword.line),
+                    Some(keyword.column),
+                    Some(&keyword.lexeme),
+                    "Couldn't lookup 'this'.",
+                )
+                .format()
+            }),
             Expr::Super {
                 id,
                 keyword: _,
                 method,
             } => {
-                let superclass: LiteralValue = environment.get("super", *id).unwrap_or_else(|| {
+                let superclass: LiteralValue = environment.get("super", *id).ok_or_else(|| {
                     PanicHandler::new(
                         Some(method.line),
                         Some(method.column),
                         Some(&method.lexeme),
                         "Couldn't lookup 'super'.",
                     )
-                    .panic();
-
-                    LiteralValue::Null
-                });
+                    .format()
+                })?;
 
                 let instance: LiteralValue = environment.get_this_instance(*id).unwrap();
 
@@ -916,46 +1107,92 @@ impl Expr {
                             method_value.to_owned(),
                         )));
                     }
-                    PanicHandler::new(
+                    return Err(PanicHandler::new(
                         Some(method.line),
                         Some(method.column),
                         Some(&name),
                         "No method named on the superclass.",
                     )
-                    .panic();
+                    .format());
                 }
 
-                PanicHandler::new(
+                Err(PanicHandler::new(
                     None,
                     None,
                     None,
                     "The superclass field on an instance was not a clazz.",
                 )
-                .panic();
-                unreachable!()
+                .format())
             }
             Expr::Grouping { id: _, expression } => expression.evaluate(environment),
+            Expr::Index {
+                id: _,
+                object,
+                bracket,
+                index,
+            } => {
+                let object: LiteralValue = object.evaluate(environment)?;
+
+                let position: f64 = match index.evaluate(environment)? {
+                    LiteralValue::Number(n) if n.fract() == 0.0 => n,
+                    value => {
+                        return Err(format!(
+                            "The index ({}) must be an integer. ({}:{})",
+                            value.convert(),
+                            bracket.line,
+                            bracket.column
+                        ))
+                    }
+                };
+
+                let (element, length): (Option<LiteralValue>, usize) = match &object {
+                    LiteralValue::List(list) => (
+                        usize::try_from(position as i64)
+                            .ok()
+                            .and_then(|i| list.get(i).cloned()),
+                        list.len(),
+                    ),
+                    LiteralValue::StringValue(s) => (
+                        usize::try_from(position as i64)
+                            .ok()
+                            .and_then(|i| s.chars().nth(i))
+                            .map(|c| LiteralValue::StringValue(c.to_string())),
+                        s.chars().count(),
+                    ),
+                    value => {
+                        return Err(format!(
+                        "Only lists and strings can be indexed, not a value of type ({}). ({}:{})",
+                        value.to_type(),
+                        bracket.line,
+                        bracket.column
+                    ))
+                    }
+                };
+
+                element.ok_or_else(|| {
+                    format!(
+                        "The index ({}) is out of bounds for a {} of length ({}). ({}:{})",
+                        position,
+                        object.to_type(),
+                        length,
+                        bracket.line,
+                        bracket.column
+                    )
+                })
+            }
             Expr::Unary {
                 id: _,
                 operator,
                 right,
             } => match (&right.evaluate(environment)?, operator.token_type) {
                 (LiteralValue::Number(x), TokenType::Minus) => Ok(LiteralValue::Number(-x)),
-                (_, TokenType::Minus) => {
-                    PanicHandler::new(
-                        None,
-                        None,
-                        None,
-                        format!(
-                            "Minus not implemented. ({}:{})",
-                            operator.line, operator.column
-                        )
-                        .as_str(),
-                    )
-                    .panic();
-                    unreachable!()
-                }
-                (any, TokenType::Bang) => Ok(any.is_false()),
+                (any, TokenType::Minus) => Err(format!(
+                    "Minus is not implemented for ({}). ({}:{})",
+                    any.to_type(),
+                    operator.line,
+                    operator.column
+                )),
+                (any, TokenType::Bang) => any.is_false(),
                 (_, type_) => Err(format!(
                     "({:?}) is not a valid operator. ({}:{})",
                     type_, operator.line, operator.column
@@ -968,17 +1205,42 @@ impl Expr {
                 operator,
                 right,
             } => {
-                match (
-                    &left.evaluate(environment)?,
-                    operator.token_type,
-                    &right.evaluate(environment)?,
-                ) {
+                let left: LiteralValue = left.evaluate(environment)?;
+                let right: LiteralValue = right.evaluate(environment)?;
+
+                let overload: Option<&str> = match operator.token_type {
+                    TokenType::Plus => Some("add"),
+                    TokenType::Minus => Some("sub"),
+                    TokenType::Star => Some("mul"),
+                    TokenType::EqualEqual | TokenType::BangEqual => Some("eq"),
+                    _ => None,
+                };
+
+                if let Some(method) = overload.and_then(|name| left.method(name)) {
+                    let result: LiteralValue = call_function(method, &[right])?;
+
+                    if operator.token_type == TokenType::BangEqual {
+                        return Ok(LiteralValue::bool(result.truthy()? == LiteralValue::False));
+                    }
+
+                    return Ok(result);
+                }
+
+                match (&left, operator.token_type, &right) {
                     (LiteralValue::Number(x), TokenType::Plus, LiteralValue::Number(y)) => {
                         Ok(LiteralValue::Number(x + y))
                     }
                     (LiteralValue::Number(x), TokenType::Minus, LiteralValue::Number(y)) => {
                         Ok(LiteralValue::Number(x - y))
                     }
+                    (
+                        LiteralValue::Number(x),
+                        TokenType::Slash | TokenType::Arith,
+                        LiteralValue::Number(y),
+                    ) if *y == 0.0 => Err(format!(
+                        "Division by zero in ({} {} 0). ({}:{})",
+                        x, operator.lexeme, operator.line, operator.column
+                    )),
                     (LiteralValue::Number(x), TokenType::Arith, LiteralValue::Number(y)) => {
                         Ok(LiteralValue::Number(x % y))
                     }
@@ -989,6 +1251,9 @@ impl Expr {
                     (LiteralValue::Number(x), TokenType::Slash, LiteralValue::Number(y)) => {
                         Ok(LiteralValue::Number(x / y))
                     }
+                    (LiteralValue::Number(x), TokenType::StarStar, LiteralValue::Number(y)) => {
+                        Ok(LiteralValue::Number(x.powf(*y)))
+                    }
                     (LiteralValue::Number(x), TokenType::Greater, LiteralValue::Number(y)) => {
                         Ok(LiteralValue::bool(x > y))
                     }
@@ -1002,24 +1267,53 @@ impl Expr {
                         Ok(LiteralValue::bool(x <= y))
                     }
 
-                    (LiteralValue::StringValue(_), op, LiteralValue::Number(_))
-                    | (LiteralValue::Number(_), op, LiteralValue::StringValue(_)) => {
-                        PanicHandler::new(
-                            None,
-                            None,
-                            None,
-                            format!("({:?}) is not defined for string and number.", op).as_str(),
-                        )
-                        .panic();
+                    (LiteralValue::StringValue(s), TokenType::Star, LiteralValue::Number(n))
+                    | (LiteralValue::Number(n), TokenType::Star, LiteralValue::StringValue(s)) => {
+                        if *n < 0.0 || n.fract() != 0.0 {
+                            return Err(format!(
+                                "The repetition count ({}) must be a positive integer or zero. ({}:{})",
+                                n, operator.line, operator.column
+                            ));
+                        }
 
-                        Ok(LiteralValue::Null)
+                        if s.len() as f64 * n > NYX_MAX_STRING_LENGTH as f64 {
+                            return Err(format!(
+                                "The result would exceed the maximum string length ({} bytes). ({}:{})",
+                                NYX_MAX_STRING_LENGTH, operator.line, operator.column
+                            ));
+                        }
+
+                        allocate(s.len() * *n as usize)?;
+
+                        Ok(LiteralValue::StringValue(s.repeat(*n as usize)))
                     }
 
-                    (
-                        LiteralValue::StringValue(s1),
-                        TokenType::Plus,
-                        LiteralValue::StringValue(s2),
-                    ) => Ok(LiteralValue::StringValue(format!("{}{}", s1, s2))),
+                    (LiteralValue::List(x), TokenType::Plus, LiteralValue::List(y)) => {
+                        allocate(x.len() + y.len())?;
+
+                        Ok(LiteralValue::List(
+                            x.iter().chain(y.iter()).cloned().collect(),
+                        ))
+                    }
+
+                    (LiteralValue::StringValue(s), TokenType::Plus, x) => {
+                        let x: String = x.display()?;
+                        allocate(s.len() + x.len())?;
+
+                        Ok(LiteralValue::StringValue(format!("{}{}", s, x)))
+                    }
+                    (x, TokenType::Plus, LiteralValue::StringValue(s)) => {
+                        let x: String = x.display()?;
+                        allocate(x.len() + s.len())?;
+
+                        Ok(LiteralValue::StringValue(format!("{}{}", x, s)))
+                    }
+
+                    (LiteralValue::StringValue(_), _, LiteralValue::Number(_))
+                    | (LiteralValue::Number(_), _, LiteralValue::StringValue(_)) => Err(format!(
+                        "({}) is not defined for string and number. ({}:{})",
+                        operator.lexeme, operator.line, operator.column
+                    )),
 
                     (x, TokenType::BangEqual, y) => Ok(LiteralValue::bool(x != y)),
                     (x, TokenType::EqualEqual, y) => Ok(LiteralValue::bool(x == y)),
@@ -1043,22 +1337,14 @@ impl Expr {
                         TokenType::LessEqual,
                         LiteralValue::StringValue(s2),
                     ) => Ok(LiteralValue::bool(s1 <= s2)),
-                    (x, _type_, y) => {
-                        PanicHandler::new(
-                            None,
-                            None,
-                            None,
-                            format!(
-                                "({}) is not implemented for operands ({}) and ({}).",
-                                operator.lexeme,
-                                x.convert(),
-                                y.convert()
-                            )
-                            .as_str(),
-                        )
-                        .panic();
-                        unreachable!()
-                    }
+                    (x, _type_, y) => Err(format!(
+                        "({}) is not implemented for operands ({}) and ({}). ({}:{})",
+                        operator.lexeme,
+                        x.convert(),
+                        y.convert(),
+                        operator.line,
+                        operator.column
+                    )),
                 }
             }
         }
@@ -1093,6 +1379,13 @@ impl PartialEq for LiteralValue {
             (LiteralValue::True, LiteralValue::True) => true,
             (LiteralValue::False, LiteralValue::False) => true,
             (LiteralValue::Null, LiteralValue::Null) => true,
+            (LiteralValue::List(x), LiteralValue::List(y)) => x == y,
+            (
+                LiteralValue::ClassInstance { fields, .. },
+                LiteralValue::ClassInstance {
+                    fields: fields2, ..
+                },
+            ) => Rc::ptr_eq(fields, fields2),
             _ => false,
         }
     }
@@ -0,0 +1,433 @@
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use super::{
+    constants::{NYX_MAX_FOLD_LENGTH, NYX_MAX_FOLD_STEPS},
+    environment::Environment,
+    expr::{with_steps, Expr, LiteralValue},
+    interpreter::NyxInterpreter,
+    resolver::Resolver,
+    stmt::Stmt,
+};
+
+pub struct Folder<'a> {
+    locals: &'a HashMap<usize, usize>,
+    pure_fcs: HashSet<String>,
+    interpreter: Option<NyxInterpreter>,
+}
+
+impl<'a> Folder<'a> {
+    pub fn new(stmts: &[Stmt], locals: &'a HashMap<usize, usize>) -> Self {
+        let mut pure_fcs: HashSet<String> = HashSet::new();
+        let mut bound: HashSet<String> = HashSet::new();
+
+        stmts.iter().for_each(|stmt| {
+            let name: Option<&str> = match stmt {
+                Stmt::Function {
+                    name, pure: true, ..
+                } => {
+                    pure_fcs.insert(name.lexeme.to_string());
+                    None
+                }
+                Stmt::Function { name, .. }
+                | Stmt::Let { name, .. }
+                | Stmt::Const { name, .. }
+                | Stmt::Clazz { name, .. } => Some(&name.lexeme),
+                _ => None,
+            };
+
+            if let Some(name) = name {
+                bound.insert(name.to_string());
+            }
+
+            if let Stmt::Destructure { names, .. } = stmt {
+                names.iter().for_each(|name| {
+                    bound.insert(name.lexeme.to_string());
+                });
+            }
+        });
+
+        pure_fcs.retain(|name| !bound.contains(name));
+
+        let program: Vec<Stmt> = stmts
+            .iter()
+            .filter(|stmt| matches!(stmt, Stmt::Function { pure: true, .. } | Stmt::Std { .. }))
+            .cloned()
+            .collect();
+
+        let interpreter: Option<NyxInterpreter> = if pure_fcs.is_empty() {
+            None
+        } else {
+            Resolver::new()
+                .resolve(&program)
+                .ok()
+                .and_then(|program_locals| {
+                    let mut interpreter: NyxInterpreter = NyxInterpreter::new();
+                    interpreter.resolve(program_locals);
+                    interpreter.interpret(program.iter().collect()).ok()?;
+
+                    Some(interpreter)
+                })
+        };
+
+        Self {
+            locals,
+            pure_fcs,
+            interpreter,
+        }
+    }
+
+    pub fn fold(&self, stmts: &[Stmt]) -> Vec<Stmt> {
+        stmts.iter().map(|stmt| self.fold_stmt(stmt)).collect()
+    }
+
+    fn fold_stmt(&self, stmt: &Stmt) -> Stmt {
+        match stmt {
+            Stmt::Expression { expr } => Stmt::Expression {
+                expr: self.fold_expr(expr),
+            },
+            Stmt::Write { exprs, newline } => Stmt::Write {
+                exprs: exprs.iter().map(|expr| self.fold_expr(expr)).collect(),
+                newline: *newline,
+            },
+            Stmt::Let { name, init } => Stmt::Let {
+                name: name.clone(),
+                init: self.fold_expr(init),
+            },
+            Stmt::Const { name, init } => Stmt::Const {
+                name: name.clone(),
+                init: self.fold_expr(init),
+            },
+            Stmt::Destructure {
+                names,
+                bracket,
+                init,
+            } => Stmt::Destructure {
+                names: names.clone(),
+                bracket: bracket.clone(),
+                init: self.fold_expr(init),
+            },
+            Stmt::Block { statements } => Stmt::Block {
+                statements: self.fold(statements),
+            },
+            Stmt::Clazz {
+                name,
+                methods,
+                superclass,
+            } => Stmt::Clazz {
+                name: name.clone(),
+                methods: self.fold(methods),
+                superclass: superclass.clone(),
+            },
+            Stmt::If {
+                predicate,
+                then,
+                elf,
+                els,
+            } => Stmt::If {
+                predicate: self.fold_expr(predicate),
+                then: Rc::new(self.fold_stmt(then)),
+                elf: elf.as_ref().map(|elf| Rc::new(self.fold_stmt(elf))),
+                els: els.as_ref().map(|els| Rc::new(self.fold_stmt(els))),
+            },
+            Stmt::Elif {
+                predicate,
+                then,
+                els,
+            } => Stmt::Elif {
+                predicate: self.fold_expr(predicate),
+                then: Rc::new(self.fold_stmt(then)),
+                els: els.as_ref().map(|els| Rc::new(self.fold_stmt(els))),
+            },
+            Stmt::While {
+                condition,
+                body,
+                increment,
+                els,
+                label,
+            } => Stmt::While {
+                condition: self.fold_expr(condition),
+                body: Rc::new(self.fold_stmt(body)),
+                increment: increment.as_ref().map(|incr| self.fold_expr(incr)),
+                els: els.as_ref().map(|els| Rc::new(self.fold_stmt(els))),
+                label: label.clone(),
+            },
+            Stmt::Function {
+                name,
+                params,
+                body,
+                pure,
+            } => Stmt::Function {
+                name: name.clone(),
+                params: params.clone(),
+                body: self.fold(body),
+                pure: *pure,
+            },
+            Stmt::Return { keyword, value } => Stmt::Return {
+                keyword: keyword.clone(),
+                value: value.as_ref().map(|value| self.fold_expr(value)),
+            },
+            Stmt::Iteration {
+                var,
+                value,
+                body,
+                label,
+            } => Stmt::Iteration {
+                var: var.clone(),
+                value: self.fold_expr(value),
+                body: Rc::new(self.fold_stmt(body)),
+                label: label.clone(),
+            },
+            Stmt::Try {
+                body,
+                catch_var,
+                catch_body,
+            } => Stmt::Try {
+                body: Rc::new(self.fold_stmt(body)),
+                catch_var: catch_var.clone(),
+                catch_body: Rc::new(self.fold_stmt(catch_body)),
+            },
+            Stmt::Throw { keyword, value } => Stmt::Throw {
+                keyword: keyword.clone(),
+                value: self.fold_expr(value),
+            },
+            Stmt::Std { .. } | Stmt::Break { .. } | Stmt::Continue { .. } => stmt.clone(),
+        }
+    }
+
+    fn fold_expr(&self, expr: &Expr) -> Expr {
+        match expr {
+            Expr::AnonFunction {
+                id,
+                paren,
+                arguments,
+                body,
+            } => Expr::AnonFunction {
+                id: *id,
+                paren: paren.clone(),
+                arguments: arguments.clone(),
+                body: self.fold(body),
+            },
+            Expr::Assign { id, name, value } => Expr::Assign {
+                id: *id,
+                name: name.clone(),
+                value: Rc::new(self.fold_expr(value)),
+            },
+            Expr::Binary {
+                id,
+                left,
+                operator,
+                right,
+            } => Self::constant(Expr::Binary {
+                id: *id,
+                left: Rc::new(self.fold_expr(left)),
+                operator: operator.clone(),
+                right: Rc::new(self.fold_expr(right)),
+            }),
+            Expr::Call {
+                id,
+                module,
+                call,
+                paren,
+                arguments,
+                named,
+            } => {
+                let folded: Expr = Expr::Call {
+                    id: *id,
+                    module: module.clone(),
+                    call: call.clone(),
+                    paren: paren.clone(),
+                    arguments: arguments.iter().map(|arg| self.fold_expr(arg)).collect(),
+                    named: named
+                        .iter()
+                        .map(|(name, arg)| (name.clone(), self.fold_expr(arg)))
+                        .collect(),
+                };
+
+                match self.evaluate(&folded) {
+                    Some(value) => Expr::Literal { value },
+                    None => folded,
+                }
+            }
+            Expr::Get { id, object, name } => Expr::Get {
+                id: *id,
+                object: Rc::new(self.fold_expr(object)),
+                name: name.clone(),
+            },
+            Expr::OptionalGet { id, object, name } => Expr::OptionalGet {
+                id: *id,
+                object: Rc::new(self.fold_expr(object)),
+                name: name.clone(),
+            },
+            Expr::Grouping { id, expression } => Self::constant(Expr::Grouping {
+                id: *id,
+                expression: Rc::new(self.fold_expr(expression)),
+            }),
+            Expr::Index {
+                id,
+                object,
+                bracket,
+                index,
+            } => Expr::Index {
+                id: *id,
+                object: Rc::new(self.fold_expr(object)),
+                bracket: bracket.clone(),
+                index: Rc::new(self.fold_expr(index)),
+            },
+            Expr::List {
+                id,
+                bracket,
+                elements,
+            } => Expr::List {
+                id: *id,
+                bracket: bracket.clone(),
+                elements: elements.iter().map(|e| self.fold_expr(e)).collect(),
+            },
+            Expr::Logical {
+                id,
+                left,
+                operator,
+                right,
+            } => Expr::Logical {
+                id: *id,
+                left: Rc::new(self.fold_expr(left)),
+                operator: operator.clone(),
+                right: Rc::new(self.fold_expr(right)),
+            },
+            Expr::Set {
+                id,
+                object,
+                name,
+                value,
+            } => Expr::Set {
+                id: *id,
+                object: Rc::new(self.fold_expr(object)),
+                name: name.clone(),
+                value: Rc::new(self.fold_expr(value)),
+            },
+            Expr::Unary {
+                id,
+                operator,
+                right,
+            } => Self::constant(Expr::Unary {
+                id: *id,
+                operator: operator.clone(),
+                right: Rc::new(self.fold_expr(right)),
+            }),
+            Expr::Update {
+                id,
+                operator,
+                target,
+                prefix,
+            } => Expr::Update {
+                id: *id,
+                operator: operator.clone(),
+                target: Rc::new(self.fold_expr(target)),
+                prefix: *prefix,
+            },
+            Expr::Literal { .. }
+            | Expr::This { .. }
+            | Expr::Super { .. }
+            | Expr::Variable { .. }
+            | Expr::ModuleProperty { .. } => expr.clone(),
+        }
+    }
+
+    fn constant(expr: Expr) -> Expr {
+        let operands: Vec<&Expr> = match &expr {
+            Expr::Binary { left, right, .. } => vec![left, right],
+            Expr::Unary { right, .. } => vec![right],
+            Expr::Grouping { expression, .. } => vec![expression],
+            _ => return expr,
+        };
+
+        let literals: bool = operands.iter().all(|operand| {
+            matches!(
+                operand,
+                Expr::Literal {
+                    value: LiteralValue::Number(_)
+                        | LiteralValue::StringValue(_)
+                        | LiteralValue::True
+                        | LiteralValue::False,
+                    ..
+                }
+            )
+        });
+
+        if !literals {
+            return expr;
+        }
+
+        match Self::bounded(|| expr.evaluate(&Environment::new(HashMap::new()))) {
+            Some(
+                value @ (LiteralValue::Number(_)
+                | LiteralValue::StringValue(_)
+                | LiteralValue::True
+                | LiteralValue::False),
+            ) if Self::embeddable(&value) => Expr::Literal { value },
+            _ => expr,
+        }
+    }
+
+    fn embeddable(value: &LiteralValue) -> bool {
+        match value {
+            LiteralValue::StringValue(s) => s.len() <= NYX_MAX_FOLD_LENGTH,
+            LiteralValue::List(list) => {
+                list.len() <= NYX_MAX_FOLD_LENGTH && list.iter().all(Self::embeddable)
+            }
+            _ => true,
+        }
+    }
+
+    fn evaluate(&self, call: &Expr) -> Option<LiteralValue> {
+        let Expr::Call {
+            module: None,
+            call: callee,
+            arguments,
+            named,
+            ..
+        } = call
+        else {
+            return None;
+        };
+
+        if !named.is_empty() {
+            return None;
+        }
+
+        let Expr::Variable { id, name } = callee.as_ref() else {
+            return None;
+        };
+
+        if !self.pure_fcs.contains(&name.lexeme)
+            || self.locals.contains_key(id)
+            || !arguments
+                .iter()
+                .all(|arg| matches!(arg, Expr::Literal { .. }))
+        {
+            return None;
+        }
+
+        let interpreter: &NyxInterpreter = self.interpreter.as_ref()?;
+
+        match Self::bounded(|| call.evaluate(&interpreter.environment))? {
+            value @ (LiteralValue::Number(_)
+            | LiteralValue::StringValue(_)
+            | LiteralValue::True
+            | LiteralValue::False
+            | LiteralValue::Null
+            | LiteralValue::List(_))
+                if Self::embeddable(&value) =>
+            {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    fn bounded(evaluate: impl FnOnce() -> Result<LiteralValue, String>) -> Option<LiteralValue> {
+        with_steps(NYX_MAX_FOLD_STEPS, evaluate).ok()
+    }
+}
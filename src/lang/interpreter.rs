@@ -1,31 +1,69 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{
+    collections::HashMap,
+    io::{stdout, Write},
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
 
 use super::{
+    constants::{NYX_ENTRY_POINT, NYX_MAX_CALL_DEPTH},
     environment::Environment,
-    expr::{CallableImpl, FunctionImpl, LiteralValue, NativeFunctionImpl},
-    libraries::{list::List, math::Math, os::OS, strings::Strings, utils::Utils},
+    expr::{set_max_depth, step, CallableImpl, FunctionImpl, LiteralValue, NativeFunctionImpl},
+    folder::Folder,
+    libraries::{
+        builtins::Builtins, color::Color, fs::Fs, json::Json, list::List, math::Math, os::OS,
+        strings::Strings, time::Time, utils::Utils,
+    },
     panic::PanicHandler,
+    parser::NyxParser,
+    resolver::Resolver,
     stmt::Stmt,
+    tokenizer::{NyxTokenizer, Token},
     types::NyxResult,
 };
 
 pub struct NyxInterpreter {
     pub specials: HashMap<&'static str, LiteralValue>,
     pub environment: Environment,
+    pub catch_panics: bool,
+    pub max_depth: usize,
 
     breaking: bool,
     continuing: bool,
+    target: Option<String>,
     returning: bool,
+    next_id: usize,
+}
+
+impl Default for NyxInterpreter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl NyxInterpreter {
     pub fn new() -> Self {
+        let environment: Environment = Environment::new(HashMap::new());
+
+        Builtins::gen_tree_methods()
+            .into_iter()
+            .for_each(|(name, fc)| {
+                environment.define(
+                    name,
+                    LiteralValue::Callable(CallableImpl::NativeFunction(fc)),
+                )
+            });
+
         Self {
             specials: HashMap::new(),
-            environment: Environment::new(HashMap::new()),
+            environment,
+            catch_panics: false,
+            max_depth: NYX_MAX_CALL_DEPTH,
             breaking: false,
             continuing: false,
+            target: None,
             returning: false,
+            next_id: 0,
         }
     }
 
@@ -33,30 +71,88 @@ impl NyxInterpreter {
         self.environment.resolve(locals);
     }
 
+    pub fn global(&self, name: &str) -> Option<LiteralValue> {
+        self.environment.get_global(name)
+    }
+
     pub fn with_env(env: Environment) -> Self {
         Self {
             specials: HashMap::new(),
             environment: env,
+            catch_panics: false,
+            max_depth: NYX_MAX_CALL_DEPTH,
             breaking: false,
             continuing: false,
+            target: None,
             returning: false,
+            next_id: 0,
+        }
+    }
+
+    /// Nyx calls recurse on the native stack of the calling thread, the default max_depth
+    /// needs one with NYX_STACK_SIZE of stack like the one the CLI runs on.
+    pub fn run_source(&mut self, source: &str) -> NyxResult<'_> {
+        set_max_depth(self.max_depth);
+
+        if !self.catch_panics {
+            return self.run_pipeline(source);
         }
+
+        panic::catch_unwind(AssertUnwindSafe(|| self.run_pipeline(source)))
+            .unwrap_or_else(|payload| Err(PanicHandler::payload(payload)))
+    }
+
+    fn run_pipeline(&mut self, source: &str) -> NyxResult<'_> {
+        self.breaking = false;
+        self.continuing = false;
+        self.target = None;
+        self.returning = false;
+
+        let mut tokenizer: NyxTokenizer = NyxTokenizer::new(source);
+        let tokens: &Vec<Token> = tokenizer.analyze()?;
+
+        let mut parser: NyxParser = NyxParser::with_id(tokens, self.next_id);
+        let stmts: Vec<Stmt> = parser.parse().map_err(|errors| errors.join("\n"))?.to_vec();
+
+        self.next_id = parser.next_id();
+
+        let resolver: Resolver = Resolver::with_globals(self.environment.names());
+        let locals: HashMap<usize, usize> = resolver.resolve(&stmts)?;
+        let stmts: Vec<Stmt> = Folder::new(&stmts, &locals).fold(&stmts);
+
+        self.resolve(locals);
+
+        self.interpret(stmts.iter().collect())
+            .and_then(|_| self.entry_point(&stmts))
+            .map_err(|any| PanicHandler::with_context(&any, source))
     }
 
-    pub fn interpret(&mut self, stmts: Vec<&Stmt>) -> NyxResult {
+    pub fn interpret(&mut self, stmts: Vec<&Stmt>) -> NyxResult<'_> {
         for stmt in stmts {
+            if self.breaking || self.continuing || self.returning {
+                break;
+            }
+
             match stmt {
                 Stmt::Expression { expr } => {
                     expr.evaluate(&self.environment)?;
                 }
-                Stmt::Write { exprs } => {
+                Stmt::Write { exprs, newline } => {
                     for expr in exprs {
-                        println!(
-                            "{}",
-                            expr.evaluate(&self.environment)?
-                                .convert()
-                                .replace("\\n", "\n")
-                        );
+                        let value: String = expr
+                            .evaluate(&self.environment)?
+                            .display()?
+                            .replace("\\n", "\n");
+
+                        if *newline {
+                            println!("{}", value);
+                        } else {
+                            print!("{}", value);
+                        }
+                    }
+
+                    if !newline {
+                        stdout().flush().map_err(|e| e.to_string())?;
                     }
                 }
                 Stmt::Let { name, init } => {
@@ -64,9 +160,41 @@ impl NyxInterpreter {
                         .define(&name.lexeme, init.evaluate(&self.environment)?);
                 }
                 Stmt::Const { name, init } => {
-                    self.environment
-                        .define(&name.lexeme, init.evaluate(&self.environment)?);
+                    self.environment.define_constant(
+                        &name.lexeme,
+                        init.evaluate(&self.environment)?,
+                        (name.line, name.column),
+                    );
                 }
+                Stmt::Destructure {
+                    names,
+                    bracket,
+                    init,
+                } => match init.evaluate(&self.environment)? {
+                    LiteralValue::List(values) => {
+                        if values.len() != names.len() {
+                            return Err(format!(
+                                "Expected ({}) values to destructure but got ({}). ({}:{})",
+                                names.len(),
+                                values.len(),
+                                bracket.line,
+                                bracket.column
+                            ));
+                        }
+
+                        names.iter().zip(values).for_each(|(name, value)| {
+                            self.environment.define(&name.lexeme, value);
+                        });
+                    }
+                    any => {
+                        return Err(format!(
+                            "Only a list can be destructured, not ({}). ({}:{})",
+                            any.to_type(),
+                            bracket.line,
+                            bracket.column
+                        ));
+                    }
+                },
                 Stmt::Block { statements } => {
                     let new: Environment = self.environment.enclose();
                     let old: Environment = self.environment.clone();
@@ -147,7 +275,7 @@ impl NyxInterpreter {
                     els,
                 } => {
                     let truth: LiteralValue = predicate.evaluate(&self.environment)?;
-                    if truth.truthy() == LiteralValue::True {
+                    if truth.truthy()? == LiteralValue::True {
                         self.interpret(vec![then])?;
                     } else if let Some(elf_stmt) = elf {
                         self.interpret(vec![elf_stmt])?;
@@ -155,69 +283,112 @@ impl NyxInterpreter {
                         self.interpret(vec![els_stmt])?;
                     }
                 }
-                Stmt::Elif { predicate, then } => {
+                Stmt::Elif {
+                    predicate,
+                    then,
+                    els,
+                } => {
                     let truth: LiteralValue = predicate.evaluate(&self.environment)?;
-                    if truth.truthy() == LiteralValue::True {
+                    if truth.truthy()? == LiteralValue::True {
                         self.interpret(vec![then])?;
+                    } else if let Some(els_stmt) = els {
+                        self.interpret(vec![els_stmt])?;
                     }
                 }
-                Stmt::While { condition, body } => {
+                Stmt::While {
+                    condition,
+                    body,
+                    increment,
+                    els,
+                    label,
+                } => {
                     let mut flag: LiteralValue = condition.evaluate(&self.environment)?;
 
-                    while flag.truthy() == LiteralValue::True {
-                        if self.breaking {
-                            break;
-                        } else if self.continuing {
-                            self.continuing = false;
-                            continue;
-                        } else if self.returning {
+                    while flag.truthy()? == LiteralValue::True {
+                        step()?;
+                        self.interpret(vec![body])?;
+
+                        if self.breaking || self.returning || !self.targets(label) {
                             break;
                         }
 
-                        self.interpret(vec![body])?;
+                        self.land(label);
+
+                        if let Some(increment) = increment {
+                            increment.evaluate(&self.environment)?;
+                        }
 
                         flag = condition.evaluate(&self.environment)?;
                     }
 
-                    self.breaking = false;
-                    self.continuing = false;
-                    self.returning = false;
+                    let completed: bool = !self.breaking && !self.continuing && !self.returning;
+
+                    self.land(label);
+
+                    if let (true, Some(els)) = (completed, els) {
+                        self.interpret(vec![els])?;
+                    }
                 }
 
-                Stmt::Iteration { var, value, body } => {
-                    if let Some(v) = self.environment.get_value(value.lexeme.clone()) {
-                        match v {
-                            LiteralValue::List(list) => {
-                                for item in list {
-                                    if self.breaking {
-                                        break;
-                                    } else if self.continuing {
-                                        self.continuing = false;
-                                        continue;
-                                    } else if self.returning {
-                                        break;
-                                    }
-
-                                    self.environment.define(&var.lexeme, item);
-                                    self.interpret(vec![body])?;
-                                }
-
-                                self.breaking = false;
-                                self.continuing = false;
-                                self.returning = false;
-                            }
-
-                            _ => {
-                                PanicHandler::new(
-                                    Some(value.line),
-                                    Some(value.column),
-                                    Some(&value.lexeme),
-                                    "The interation value is not iterable.",
-                                )
-                                .panic();
-                            }
+                Stmt::Iteration {
+                    var,
+                    value,
+                    body,
+                    label,
+                } => {
+                    let items: Vec<LiteralValue> = match value.evaluate(&self.environment)? {
+                        LiteralValue::List(list) => list,
+                        LiteralValue::StringValue(s) => s
+                            .chars()
+                            .map(|c| LiteralValue::StringValue(c.to_string()))
+                            .collect(),
+                        v => {
+                            return Err(format!(
+                                "The iteration value ({}) is not iterable. ({}:{})",
+                                v.convert(),
+                                var.line,
+                                var.column
+                            ))
+                        }
+                    };
+
+                    for item in items {
+                        step()?;
+                        self.environment.define(&var.lexeme, item);
+                        self.interpret(vec![body])?;
+
+                        if self.breaking || self.returning || !self.targets(label) {
+                            break;
                         }
+
+                        self.land(label);
                     }
+
+                    self.land(label);
+                }
+                Stmt::Try {
+                    body,
+                    catch_var,
+                    catch_body,
+                } => {
+                    if let Err(any) = self.interpret(vec![body]) {
+                        let new: Environment = self.environment.enclose();
+                        let old: Environment = self.environment.clone();
+
+                        self.environment = new;
+                        self.environment.define(
+                            &catch_var.lexeme,
+                            LiteralValue::StringValue(any.trim().to_string()),
+                        );
+
+                        let catch: NyxResult = self.interpret(vec![catch_body]);
+                        self.environment = old;
+
+                        catch?;
+                    }
+                }
+                Stmt::Throw { keyword: _, value } => {
+                    return Err(value.evaluate(&self.environment)?.convert());
                 }
                 Stmt::Function { name, .. } => {
                     self.environment.define(
@@ -243,6 +414,10 @@ impl NyxInterpreter {
                         "math" => self.math(fc.clone().unwrap().as_slice()),
                         "utils" => self.utils(fc.clone().unwrap().as_slice()),
                         "string" => self.string(fc.clone().unwrap().as_slice()),
+                        "color" => self.color(fc.clone().unwrap().as_slice()),
+                        "fs" => self.fs(fc.clone().unwrap().as_slice()),
+                        "json" => self.json(fc.clone().unwrap().as_slice()),
+                        "time" => self.time(fc.clone().unwrap().as_slice()),
 
                         _ => {
                             PanicHandler::new(
@@ -298,6 +473,42 @@ impl NyxInterpreter {
                             },
                         ),
 
+                        "color" => self.environment.define(
+                            "color",
+                            LiteralValue::Module {
+                                name: "color",
+                                methods: Color::gen_tree_methods(),
+                                constants: None,
+                            },
+                        ),
+
+                        "fs" => self.environment.define(
+                            "fs",
+                            LiteralValue::Module {
+                                name: "fs",
+                                methods: Fs::gen_tree_methods(),
+                                constants: Some(Fs::gen_tree_constants()),
+                            },
+                        ),
+
+                        "json" => self.environment.define(
+                            "json",
+                            LiteralValue::Module {
+                                name: "json",
+                                methods: Json::gen_tree_methods(),
+                                constants: None,
+                            },
+                        ),
+
+                        "time" => self.environment.define(
+                            "time",
+                            LiteralValue::Module {
+                                name: "time",
+                                methods: Time::gen_tree_methods(),
+                                constants: None,
+                            },
+                        ),
+
                         _ => {
                             PanicHandler::new(
                                 None,
@@ -310,55 +521,193 @@ impl NyxInterpreter {
                     },
                 },
 
-                Stmt::Break { .. } => self.breaking = true,
-                Stmt::Continue { .. } => self.continuing = true,
+                Stmt::Break { label } => {
+                    self.breaking = true;
+                    self.target = label.as_ref().map(|label| label.lexeme.clone());
+                }
+                Stmt::Continue { label } => {
+                    self.continuing = true;
+                    self.target = label.as_ref().map(|label| label.lexeme.clone());
+                }
             };
         }
 
         Ok(())
     }
 
+    fn targets(&self, label: &Option<Token>) -> bool {
+        match (&self.target, label) {
+            (None, _) => true,
+            (Some(target), Some(label)) => *target == label.lexeme,
+            (Some(_), None) => false,
+        }
+    }
+
+    fn land(&mut self, label: &Option<Token>) {
+        if self.targets(label) {
+            self.breaking = false;
+            self.continuing = false;
+            self.target = None;
+        }
+    }
+
+    pub fn entry_point(&self, stmts: &[Stmt]) -> NyxResult<'_> {
+        let has_main: bool = stmts.iter().any(
+            |stmt| matches!(stmt, Stmt::Function { name, .. } if name.lexeme == NYX_ENTRY_POINT),
+        );
+
+        let declarations_only: bool = stmts.iter().all(|stmt| {
+            matches!(
+                stmt,
+                Stmt::Function { .. }
+                    | Stmt::Clazz { .. }
+                    | Stmt::Std { .. }
+                    | Stmt::Let { .. }
+                    | Stmt::Const { .. }
+                    | Stmt::Destructure { .. }
+            )
+        });
+
+        if !has_main || !declarations_only {
+            return Ok(());
+        }
+
+        match self.environment.get_global(NYX_ENTRY_POINT) {
+            Some(LiteralValue::Callable(main)) => {
+                main.call(&[])?;
+                Ok(())
+            }
+            _ => Err(format!(
+                "The entry point ({}) is not a function.",
+                NYX_ENTRY_POINT
+            )),
+        }
+    }
+
     fn string(&self, invoke: &[String]) {
         invoke.iter().for_each(|f| match f.as_str() {
             "length" => {
-                self.environment
-                    .define("length", self.build_native_fc("length", Strings::length));
+                self.environment.define(
+                    "length",
+                    self.build_fallible_native_fc("length", Strings::length),
+                );
             }
 
             "split" => {
-                self.environment
-                    .define("split", self.build_native_fc("split", Strings::split));
+                self.environment.define(
+                    "split",
+                    self.build_fallible_native_fc("split", Strings::split),
+                );
             }
 
             "find" => {
                 self.environment
-                    .define("find", self.build_native_fc("find", Strings::find));
+                    .define("find", self.build_fallible_native_fc("find", Strings::find));
             }
 
             "replace" => {
-                self.environment
-                    .define("replace", self.build_native_fc("replace", Strings::replace));
+                self.environment.define(
+                    "replace",
+                    self.build_fallible_native_fc("replace", Strings::replace),
+                );
             }
 
             "push" => {
                 self.environment
-                    .define("push", self.build_native_fc("push", Strings::push));
+                    .define("push", self.build_fallible_native_fc("push", Strings::push));
             }
 
             "trim" => {
                 self.environment
-                    .define("trim", self.build_native_fc("trim", Strings::trim));
+                    .define("trim", self.build_fallible_native_fc("trim", Strings::trim));
             }
 
             "trim_l" => {
-                self.environment
-                    .define("trim_l", self.build_native_fc("trim_l", Strings::trim_left));
+                self.environment.define(
+                    "trim_l",
+                    self.build_fallible_native_fc("trim_l", Strings::trim_left),
+                );
             }
 
             "trim_r" => {
                 self.environment.define(
                     "trim_r",
-                    self.build_native_fc("trim_r", Strings::trim_right),
+                    self.build_fallible_native_fc("trim_r", Strings::trim_right),
+                );
+            }
+
+            "codepoints" => {
+                self.environment.define(
+                    "codepoints",
+                    self.build_fallible_native_fc("codepoints", Strings::codepoints),
+                );
+            }
+
+            "contains" => {
+                self.environment.define(
+                    "contains",
+                    self.build_fallible_native_fc("contains", Strings::contains),
+                );
+            }
+
+            "starts_with" => {
+                self.environment.define(
+                    "starts_with",
+                    self.build_fallible_native_fc("starts_with", Strings::starts_with),
+                );
+            }
+
+            "ends_with" => {
+                self.environment.define(
+                    "ends_with",
+                    self.build_fallible_native_fc("ends_with", Strings::ends_with),
+                );
+            }
+
+            "substring" => {
+                self.environment.define(
+                    "substring",
+                    self.build_fallible_native_fc("substring", Strings::substring),
+                );
+            }
+
+            "repeat" => {
+                self.environment.define(
+                    "repeat",
+                    self.build_fallible_native_fc("repeat", Strings::repeat),
+                );
+            }
+
+            "visible_length" => {
+                self.environment.define(
+                    "visible_length",
+                    self.build_fallible_native_fc("visible_length", Strings::visible_length),
+                );
+            }
+
+            "swap_case" => {
+                self.environment.define(
+                    "swap_case",
+                    self.build_fallible_native_fc("swap_case", Strings::swap_case),
+                );
+            }
+
+            "expand_tabs" => {
+                self.environment.define(
+                    "expand_tabs",
+                    self.build_fallible_native_fc("expand_tabs", Strings::expand_tabs),
+                );
+            }
+
+            "fit" => {
+                self.environment
+                    .define("fit", self.build_fallible_native_fc("fit", Strings::fit));
+            }
+
+            "char_at" => {
+                self.environment.define(
+                    "char_at",
+                    self.build_fallible_native_fc("char_at", Strings::char_at),
                 );
             }
 
@@ -382,27 +731,111 @@ impl NyxInterpreter {
             }
             "size" => {
                 self.environment
-                    .define("size", self.build_native_fc("size", List::size));
+                    .define("size", self.build_fallible_native_fc("size", List::size));
             }
             "add" => {
                 self.environment
-                    .define("add", self.build_native_fc("add", List::add));
+                    .define("add", self.build_fallible_native_fc("add", List::add));
             }
             "reverse" => {
-                self.environment
-                    .define("reverse", self.build_native_fc("reverse", List::reverse));
+                self.environment.define(
+                    "reverse",
+                    self.build_fallible_native_fc("reverse", List::reverse),
+                );
             }
             "get" => {
                 self.environment
-                    .define("get", self.build_native_fc("get", List::get));
+                    .define("get", self.build_fallible_native_fc("get", List::get));
             }
             "pop" => {
                 self.environment
-                    .define("pop", self.build_native_fc("pop", List::pop));
+                    .define("pop", self.build_fallible_native_fc("pop", List::pop));
             }
             "remove" => {
+                self.environment.define(
+                    "remove",
+                    self.build_fallible_native_fc("remove", List::remove),
+                );
+            }
+            "windows" => {
+                self.environment.define(
+                    "windows",
+                    self.build_fallible_native_fc("windows", List::windows),
+                );
+            }
+            "map" => {
+                self.environment
+                    .define("map", self.build_fallible_native_fc("map", List::map));
+            }
+            "filter" => {
+                self.environment.define(
+                    "filter",
+                    self.build_fallible_native_fc("filter", List::filter),
+                );
+            }
+            "reduce" => {
+                self.environment.define(
+                    "reduce",
+                    self.build_fallible_native_fc("reduce", List::reduce),
+                );
+            }
+            "join_deep" => {
+                self.environment.define(
+                    "join_deep",
+                    self.build_fallible_native_fc("join_deep", List::join_deep),
+                );
+            }
+            "sort" => {
                 self.environment
-                    .define("remove", self.build_native_fc("remove", List::remove));
+                    .define("sort", self.build_fallible_native_fc("sort", List::sort));
+            }
+            "join" => {
+                self.environment
+                    .define("join", self.build_fallible_native_fc("join", List::join));
+            }
+            "frequencies" => {
+                self.environment.define(
+                    "frequencies",
+                    self.build_fallible_native_fc("frequencies", List::frequencies),
+                );
+            }
+            "insert" => {
+                self.environment.define(
+                    "insert",
+                    self.build_fallible_native_fc("insert", List::insert),
+                );
+            }
+            "range" => {
+                self.environment
+                    .define("range", self.build_fallible_native_fc("range", List::range));
+            }
+            "scan" => {
+                self.environment
+                    .define("scan", self.build_fallible_native_fc("scan", List::scan));
+            }
+            "max_index" => {
+                self.environment.define(
+                    "max_index",
+                    self.build_fallible_native_fc("max_index", List::max_index),
+                );
+            }
+            "min_index" => {
+                self.environment.define(
+                    "min_index",
+                    self.build_fallible_native_fc("min_index", List::min_index),
+                );
+            }
+            "flatten" => {
+                self.environment.define(
+                    "flatten",
+                    self.build_fallible_native_fc("flatten", List::flatten),
+                );
+            }
+            "concat" => {
+                self.environment.define(
+                    "concat",
+                    self.build_fallible_native_fc("concat", List::concat),
+                );
             }
             _ => {
                 PanicHandler::new(
@@ -420,17 +853,23 @@ impl NyxInterpreter {
         invoke.iter().for_each(|f| match f.as_str() {
             "exit" => {
                 self.environment
-                    .define("exit", self.build_native_fc("exit", OS::exit));
+                    .define("exit", self.build_fallible_native_fc("exit", OS::exit));
             }
             "current_time" => {
                 self.environment.define(
                     "current_time",
-                    self.build_native_fc("current_time", OS::current_time),
+                    self.build_fallible_native_fc("current_time", OS::current_time),
                 );
             }
             "input" => {
                 self.environment
-                    .define("input", self.build_native_fc("input", OS::input));
+                    .define("input", self.build_fallible_native_fc("input", OS::input));
+            }
+            "terminal_size" => {
+                self.environment.define(
+                    "terminal_size",
+                    self.build_native_fc("terminal_size", OS::terminal_size),
+                );
             }
             "name" => self.environment.define(
                 "name",
@@ -441,6 +880,37 @@ impl NyxInterpreter {
                 LiteralValue::StringValue(std::env::consts::ARCH.to_string()),
             ),
 
+            "getcwd" => {
+                self.environment.define(
+                    "getcwd",
+                    self.build_fallible_native_fc("getcwd", OS::getcwd),
+                );
+            }
+
+            "chdir" => {
+                self.environment
+                    .define("chdir", self.build_fallible_native_fc("chdir", OS::chdir));
+            }
+
+            "sleep" => {
+                self.environment
+                    .define("sleep", self.build_fallible_native_fc("sleep", OS::sleep));
+            }
+
+            "home_dir" => {
+                self.environment.define(
+                    "home_dir",
+                    self.build_fallible_native_fc("home_dir", OS::home_dir),
+                );
+            }
+
+            "join_path" => {
+                self.environment.define(
+                    "join_path",
+                    self.build_fallible_native_fc("join_path", OS::join_path),
+                );
+            }
+
             _ => {
                 PanicHandler::new(
                     None,
@@ -457,7 +927,7 @@ impl NyxInterpreter {
         invoke.iter().for_each(|f| match f.as_str() {
             "sqrt" => {
                 self.environment
-                    .define("sqrt", self.build_native_fc("sqrt", Math::sqrt));
+                    .define("sqrt", self.build_fallible_native_fc("sqrt", Math::sqrt));
             }
 
             "E" => self
@@ -472,9 +942,71 @@ impl NyxInterpreter {
                 .environment
                 .define("TAU", LiteralValue::Number(std::f64::consts::TAU)),
 
+            "SQRT2" => self
+                .environment
+                .define("SQRT2", LiteralValue::Number(std::f64::consts::SQRT_2)),
+
+            "LN2" => self
+                .environment
+                .define("LN2", LiteralValue::Number(std::f64::consts::LN_2)),
+
+            "LN10" => self
+                .environment
+                .define("LN10", LiteralValue::Number(std::f64::consts::LN_10)),
+
+            "INFINITY" => self
+                .environment
+                .define("INFINITY", LiteralValue::Number(f64::INFINITY)),
+
+            "NEG_INFINITY" => self
+                .environment
+                .define("NEG_INFINITY", LiteralValue::Number(f64::NEG_INFINITY)),
+
             "pow" => {
                 self.environment
-                    .define("pow", self.build_native_fc("pow", Math::pow));
+                    .define("pow", self.build_fallible_native_fc("pow", Math::pow));
+            }
+
+            "is_infinite" => {
+                self.environment.define(
+                    "is_infinite",
+                    self.build_fallible_native_fc("is_infinite", Math::is_infinite),
+                );
+            }
+
+            "approx_eq" => {
+                self.environment.define(
+                    "approx_eq",
+                    self.build_fallible_native_fc("approx_eq", Math::approx_eq),
+                );
+            }
+
+            "round_half_even" => {
+                self.environment.define(
+                    "round_half_even",
+                    self.build_fallible_native_fc("round_half_even", Math::round_half_even),
+                );
+            }
+
+            "mod_pow" => {
+                self.environment.define(
+                    "mod_pow",
+                    self.build_fallible_native_fc("mod_pow", Math::mod_pow),
+                );
+            }
+
+            "is_nan" => {
+                self.environment.define(
+                    "is_nan",
+                    self.build_fallible_native_fc("is_nan", Math::is_nan),
+                );
+            }
+
+            "is_finite" => {
+                self.environment.define(
+                    "is_finite",
+                    self.build_fallible_native_fc("is_finite", Math::is_finite),
+                );
             }
 
             _ => {
@@ -492,13 +1024,240 @@ impl NyxInterpreter {
     fn utils(&self, invoke: &[String]) {
         invoke.iter().for_each(|f| match f.as_str() {
             "type" => {
+                self.environment.define(
+                    "type",
+                    self.build_fallible_native_fc("type", Utils::get_type),
+                );
+            }
+
+            "parse" => {
+                self.environment.define(
+                    "parse",
+                    self.build_fallible_native_fc("parse", Utils::parse),
+                );
+            }
+
+            "copy_fields" => {
+                self.environment.define(
+                    "copy_fields",
+                    self.build_fallible_native_fc("copy_fields", Utils::copy_fields),
+                );
+            }
+
+            "ensure" => {
+                self.environment.define(
+                    "ensure",
+                    self.build_fallible_native_fc("ensure", Utils::ensure),
+                );
+            }
+
+            "sort_keys" => {
+                self.environment.define(
+                    "sort_keys",
+                    self.build_fallible_native_fc("sort_keys", Utils::sort_keys),
+                );
+            }
+
+            "format_number" => {
+                self.environment.define(
+                    "format_number",
+                    self.build_fallible_native_fc("format_number", Utils::format_number),
+                );
+            }
+
+            "assert" => {
+                self.environment.define(
+                    "assert",
+                    self.build_fallible_native_fc("assert", Utils::assert),
+                );
+            }
+
+            "assert_eq" => {
+                self.environment.define(
+                    "assert_eq",
+                    self.build_fallible_native_fc("assert_eq", Utils::assert_eq),
+                );
+            }
+
+            "freeze" => {
+                self.environment.define(
+                    "freeze",
+                    self.build_fallible_native_fc("freeze", Utils::freeze),
+                );
+            }
+
+            "is_null" => {
+                self.environment.define(
+                    "is_null",
+                    self.build_fallible_native_fc("is_null", Utils::is_null),
+                );
+            }
+
+            "is_number" => {
+                self.environment.define(
+                    "is_number",
+                    self.build_fallible_native_fc("is_number", Utils::is_number),
+                );
+            }
+
+            "is_string" => {
+                self.environment.define(
+                    "is_string",
+                    self.build_fallible_native_fc("is_string", Utils::is_string),
+                );
+            }
+
+            "is_bool" => {
+                self.environment.define(
+                    "is_bool",
+                    self.build_fallible_native_fc("is_bool", Utils::is_bool),
+                );
+            }
+
+            "is_list" => {
+                self.environment.define(
+                    "is_list",
+                    self.build_fallible_native_fc("is_list", Utils::is_list),
+                );
+            }
+
+            "is_callable" => {
+                self.environment.define(
+                    "is_callable",
+                    self.build_fallible_native_fc("is_callable", Utils::is_callable),
+                );
+            }
+
+            "to_bool" => {
+                self.environment.define(
+                    "to_bool",
+                    self.build_fallible_native_fc("to_bool", Utils::to_bool),
+                );
+            }
+
+            "to_int" => {
+                self.environment.define(
+                    "to_int",
+                    self.build_fallible_native_fc("to_int", Utils::to_int),
+                );
+            }
+
+            "fields" => {
+                self.environment.define(
+                    "fields",
+                    self.build_fallible_native_fc("fields", Utils::fields),
+                );
+            }
+
+            _ => {
+                PanicHandler::new(
+                    None,
+                    None,
+                    None,
+                    "Uknown function or constant in the importation of the module Utils.",
+                )
+                .panic();
+            }
+        });
+    }
+
+    fn color(&self, invoke: &[String]) {
+        invoke.iter().for_each(|f| match f.as_str() {
+            "red" => {
                 self.environment
-                    .define("type", self.build_native_fc("type", Utils::get_type));
+                    .define("red", self.build_fallible_native_fc("red", Color::red));
+            }
+
+            "green" => {
+                self.environment.define(
+                    "green",
+                    self.build_fallible_native_fc("green", Color::green),
+                );
+            }
+
+            "yellow" => {
+                self.environment.define(
+                    "yellow",
+                    self.build_fallible_native_fc("yellow", Color::yellow),
+                );
+            }
+
+            "blue" => {
+                self.environment
+                    .define("blue", self.build_fallible_native_fc("blue", Color::blue));
+            }
+
+            "bold" => {
+                self.environment
+                    .define("bold", self.build_fallible_native_fc("bold", Color::bold));
+            }
+
+            "rgb" => {
+                self.environment
+                    .define("rgb", self.build_fallible_native_fc("rgb", Color::rgb));
+            }
+
+            _ => {
+                PanicHandler::new(
+                    None,
+                    None,
+                    None,
+                    "Uknown function or constant in the importation of the module Color.",
+                )
+                .panic();
+            }
+        });
+    }
+
+    fn fs(&self, invoke: &[String]) {
+        invoke.iter().for_each(|f| match f.as_str() {
+            "read_file" => {
+                self.environment.define(
+                    "read_file",
+                    self.build_fallible_native_fc("read_file", Fs::read_file),
+                );
+            }
+
+            "write_file" => {
+                self.environment.define(
+                    "write_file",
+                    self.build_fallible_native_fc("write_file", Fs::write_file),
+                );
+            }
+
+            "exists" => {
+                self.environment.define(
+                    "exists",
+                    self.build_fallible_native_fc("exists", Fs::exists),
+                );
+            }
+
+            "temp_dir" => self.environment.define("temp_dir", Fs::temp_dir()),
+
+            _ => {
+                PanicHandler::new(
+                    None,
+                    None,
+                    None,
+                    "Uknown function or constant in the importation of the module Fs.",
+                )
+                .panic();
+            }
+        });
+    }
+
+    fn json(&self, invoke: &[String]) {
+        invoke.iter().for_each(|f| match f.as_str() {
+            "stringify" => {
+                self.environment.define(
+                    "stringify",
+                    self.build_fallible_native_fc("stringify", Json::stringify),
+                );
             }
 
             "parse" => {
                 self.environment
-                    .define("parse", self.build_native_fc("parse", Utils::parse));
+                    .define("parse", self.build_fallible_native_fc("parse", Json::parse));
             }
 
             _ => {
@@ -506,7 +1265,42 @@ impl NyxInterpreter {
                     None,
                     None,
                     None,
-                    "Uknown function or constant in the importation of the module Utils.",
+                    "Uknown function or constant in the importation of the module Json.",
+                )
+                .panic();
+            }
+        });
+    }
+
+    fn time(&self, invoke: &[String]) {
+        invoke.iter().for_each(|f| match f.as_str() {
+            "now_millis" => {
+                self.environment.define(
+                    "now_millis",
+                    self.build_fallible_native_fc("now_millis", Time::now_millis),
+                );
+            }
+
+            "now_secs" => {
+                self.environment.define(
+                    "now_secs",
+                    self.build_fallible_native_fc("now_secs", Time::now_secs),
+                );
+            }
+
+            "format" => {
+                self.environment.define(
+                    "format",
+                    self.build_fallible_native_fc("format", Time::format),
+                );
+            }
+
+            _ => {
+                PanicHandler::new(
+                    None,
+                    None,
+                    None,
+                    "Uknown function or constant in the importation of the module Time.",
                 )
                 .panic();
             }
@@ -516,6 +1310,13 @@ impl NyxInterpreter {
     fn build_native_fc<F>(&self, name: &'static str, fc: F) -> LiteralValue
     where
         F: Fn(&[LiteralValue]) -> LiteralValue + 'static,
+    {
+        self.build_fallible_native_fc(name, move |args| Ok(fc(args)))
+    }
+
+    fn build_fallible_native_fc<F>(&self, name: &'static str, fc: F) -> LiteralValue
+    where
+        F: Fn(&[LiteralValue]) -> Result<LiteralValue, String> + 'static,
     {
         LiteralValue::Callable(CallableImpl::NativeFunction(NativeFunctionImpl {
             name,
@@ -524,7 +1325,10 @@ impl NyxInterpreter {
     }
 
     fn build_fc(&self, stmt: &Stmt) -> FunctionImpl {
-        if let Stmt::Function { name, params, body } = stmt {
+        if let Stmt::Function {
+            name, params, body, ..
+        } = stmt
+        {
             return FunctionImpl {
                 name: name.lexeme.clone(),
                 arity: params.len() as u8,
@@ -0,0 +1,89 @@
+use std::{collections::HashMap, rc::Rc};
+
+use super::{
+    super::{
+        expr::{LiteralValue, NativeFunctionImpl},
+        panic::PanicHandler,
+    },
+    os::OS,
+    utils::Utils,
+};
+
+pub struct Builtins;
+
+impl Builtins {
+    pub fn gen_tree_methods() -> HashMap<&'static str, NativeFunctionImpl> {
+        let mut methods: HashMap<&'static str, NativeFunctionImpl> = HashMap::new();
+
+        methods.insert(
+            "exit",
+            NativeFunctionImpl {
+                name: "exit",
+                fc: Rc::new(Self::exit),
+            },
+        );
+
+        methods.insert(
+            "abort",
+            NativeFunctionImpl {
+                name: "abort",
+                fc: Rc::new(Self::abort),
+            },
+        );
+
+        methods.insert(
+            "type",
+            NativeFunctionImpl {
+                name: "type",
+                fc: Rc::new(Utils::get_type),
+            },
+        );
+
+        methods.insert(
+            "parse",
+            NativeFunctionImpl {
+                name: "parse",
+                fc: Rc::new(Utils::parse),
+            },
+        );
+
+        methods.insert(
+            "len",
+            NativeFunctionImpl {
+                name: "len",
+                fc: Rc::new(Self::len),
+            },
+        );
+
+        methods
+    }
+
+    pub fn exit(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        OS::exit_with("exit", args)
+    }
+
+    pub fn abort(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(abort()) Should must have 1 argument.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(message) => {
+                PanicHandler::new(None, None, None, message).exit()
+            }
+            _ => Err("(abort()) The correctly arguments are (message string).".to_string()),
+        }
+    }
+
+    pub fn len(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(len()) Should must have 1 argument.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(s) => Ok(LiteralValue::Number(s.chars().count() as f64)),
+            LiteralValue::List(list) => Ok(LiteralValue::Number(list.len() as f64)),
+            _ => Err("(len()) The correctly arguments are (string or list).".to_string()),
+        }
+    }
+}
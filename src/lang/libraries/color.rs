@@ -0,0 +1,134 @@
+use std::{collections::HashMap, rc::Rc};
+
+use colored::{ColoredString, Colorize};
+
+use super::super::expr::{LiteralValue, NativeFunctionImpl};
+
+pub struct Color;
+
+impl Color {
+    pub fn gen_tree_methods() -> HashMap<&'static str, NativeFunctionImpl> {
+        let mut methods: HashMap<&'static str, NativeFunctionImpl> = HashMap::new();
+
+        methods.insert(
+            "red",
+            NativeFunctionImpl {
+                name: "red",
+                fc: Rc::new(Self::red),
+            },
+        );
+
+        methods.insert(
+            "green",
+            NativeFunctionImpl {
+                name: "green",
+                fc: Rc::new(Self::green),
+            },
+        );
+
+        methods.insert(
+            "yellow",
+            NativeFunctionImpl {
+                name: "yellow",
+                fc: Rc::new(Self::yellow),
+            },
+        );
+
+        methods.insert(
+            "blue",
+            NativeFunctionImpl {
+                name: "blue",
+                fc: Rc::new(Self::blue),
+            },
+        );
+
+        methods.insert(
+            "bold",
+            NativeFunctionImpl {
+                name: "bold",
+                fc: Rc::new(Self::bold),
+            },
+        );
+
+        methods.insert(
+            "rgb",
+            NativeFunctionImpl {
+                name: "rgb",
+                fc: Rc::new(Self::rgb),
+            },
+        );
+
+        methods
+    }
+
+    pub fn red(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::paint("red", args, |s| s.red())
+    }
+
+    pub fn green(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::paint("green", args, |s| s.green())
+    }
+
+    pub fn yellow(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::paint("yellow", args, |s| s.yellow())
+    }
+
+    pub fn blue(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::paint("blue", args, |s| s.blue())
+    }
+
+    pub fn bold(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::paint("bold", args, |s| s.bold())
+    }
+
+    pub fn rgb(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 4 {
+            return Err("(color::rgb()) Should must have 4 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1], &args[2], &args[3]) {
+            (
+                LiteralValue::StringValue(s),
+                LiteralValue::Number(r),
+                LiteralValue::Number(g),
+                LiteralValue::Number(b),
+            ) => {
+                if [r, g, b]
+                    .iter()
+                    .any(|c| **c < 0.0 || **c > 255.0 || c.fract() != 0.0)
+                {
+                    return Err(
+                        "(color::rgb()) Each channel must be an integer between 0 and 255."
+                            .to_string(),
+                    );
+                }
+
+                Ok(LiteralValue::StringValue(
+                    s.truecolor(*r as u8, *g as u8, *b as u8).to_string(),
+                ))
+            }
+            _ => Err(
+                "(color::rgb()) The correctly arguments are (source string, red number, green number, blue number)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    fn paint(
+        name: &str,
+        args: &[LiteralValue],
+        fc: fn(&str) -> ColoredString,
+    ) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err(format!("(color::{}()) Should must have 1 arguments.", name));
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(s) => Ok(LiteralValue::StringValue(fc(s).to_string())),
+            _ => Err(format!(
+                "(color::{}()) The correctly arguments are (source string).",
+                name
+            )),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    collections::HashMap,
+    env::temp_dir,
+    fs::{read_to_string, write},
+    path::Path,
+    rc::Rc,
+};
+
+use super::super::expr::{LiteralValue, NativeFunctionImpl};
+
+pub struct Fs;
+
+impl Fs {
+    pub fn gen_tree_methods() -> HashMap<&'static str, NativeFunctionImpl> {
+        let mut methods: HashMap<&'static str, NativeFunctionImpl> = HashMap::new();
+
+        methods.insert(
+            "read_file",
+            NativeFunctionImpl {
+                name: "read_file",
+                fc: Rc::new(Self::read_file),
+            },
+        );
+
+        methods.insert(
+            "write_file",
+            NativeFunctionImpl {
+                name: "write_file",
+                fc: Rc::new(Self::write_file),
+            },
+        );
+
+        methods.insert(
+            "exists",
+            NativeFunctionImpl {
+                name: "exists",
+                fc: Rc::new(Self::exists),
+            },
+        );
+
+        methods
+    }
+
+    pub fn gen_tree_constants() -> HashMap<&'static str, LiteralValue> {
+        let mut constants: HashMap<&'static str, LiteralValue> = HashMap::new();
+
+        constants.insert("temp_dir", Self::temp_dir());
+
+        constants
+    }
+
+    pub fn temp_dir() -> LiteralValue {
+        LiteralValue::StringValue(temp_dir().to_string_lossy().to_string())
+    }
+
+    pub fn read_file(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(fs::read_file()) Should must have 1 argument.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(path) => read_to_string(path)
+                .map(LiteralValue::StringValue)
+                .map_err(|e| {
+                    format!(
+                        "(fs::read_file()) Could not read the file ({}): {}.",
+                        path, e
+                    )
+                }),
+            _ => Err("(fs::read_file()) The correctly arguments are (path string).".to_string()),
+        }
+    }
+
+    pub fn write_file(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(fs::write_file()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::StringValue(path), LiteralValue::StringValue(content)) => {
+                write(path, content).map_err(|e| {
+                    format!(
+                        "(fs::write_file()) Could not write the file ({}): {}.",
+                        path, e
+                    )
+                })?;
+
+                Ok(LiteralValue::Null)
+            }
+            _ => Err(
+                "(fs::write_file()) The correctly arguments are (path string, content string)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn exists(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(fs::exists()) Should must have 1 argument.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(path) => {
+                if Path::new(path).exists() {
+                    return Ok(LiteralValue::True);
+                }
+
+                Ok(LiteralValue::False)
+            }
+            _ => Err("(fs::exists()) The correctly arguments are (path string).".to_string()),
+        }
+    }
+}
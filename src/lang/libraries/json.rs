@@ -0,0 +1,417 @@
+use std::{collections::HashMap, iter::Peekable, rc::Rc, str::CharIndices};
+
+use super::super::expr::{LiteralValue, NativeFunctionImpl};
+
+pub struct Json;
+
+impl Json {
+    pub fn gen_tree_methods() -> HashMap<&'static str, NativeFunctionImpl> {
+        let mut methods: HashMap<&'static str, NativeFunctionImpl> = HashMap::new();
+
+        methods.insert(
+            "stringify",
+            NativeFunctionImpl {
+                name: "stringify",
+                fc: Rc::new(Self::stringify),
+            },
+        );
+
+        methods.insert(
+            "parse",
+            NativeFunctionImpl {
+                name: "parse",
+                fc: Rc::new(Self::parse),
+            },
+        );
+
+        methods
+    }
+
+    pub fn stringify(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(json::stringify()) Should must have 1 argument.".to_string());
+        }
+
+        let mut out: String = String::new();
+
+        Self::write_value(&args[0], &mut out)?;
+
+        Ok(LiteralValue::StringValue(out))
+    }
+
+    pub fn parse(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(json::parse()) Should must have 1 argument.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(source) => {
+                let mut reader: JsonReader = JsonReader {
+                    source,
+                    chars: source.char_indices().peekable(),
+                };
+
+                let value: LiteralValue = reader.value()?;
+
+                reader.skip_whitespace();
+
+                if let Some((position, _)) = reader.chars.peek().copied() {
+                    return Err(reader.error(position, "unexpected trailing characters"));
+                }
+
+                Ok(value)
+            }
+            _ => Err("(json::parse()) The correctly arguments are (source string).".to_string()),
+        }
+    }
+
+    fn write_value(value: &LiteralValue, out: &mut String) -> Result<(), String> {
+        match value {
+            LiteralValue::Number(n) => {
+                if !n.is_finite() {
+                    return Err(format!(
+                        "(json::stringify()) The number ({}) cannot be represented in JSON.",
+                        value.convert()
+                    ));
+                }
+
+                out.push_str(&n.to_string());
+            }
+            LiteralValue::StringValue(s) => Self::write_string(s, out),
+            LiteralValue::True => out.push_str("true"),
+            LiteralValue::False => out.push_str("false"),
+            LiteralValue::Null => out.push_str("null"),
+            LiteralValue::List(list) => {
+                out.push('[');
+
+                for (index, element) in list.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+
+                    Self::write_value(element, out)?;
+                }
+
+                out.push(']');
+            }
+            LiteralValue::ClassInstance { fields, .. } => {
+                out.push('{');
+
+                for (index, (name, field)) in fields.borrow().iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+
+                    Self::write_string(name, out);
+                    out.push(':');
+                    Self::write_value(field, out)?;
+                }
+
+                out.push('}');
+            }
+            _ => {
+                return Err(format!(
+                    "(json::stringify()) A value of type ({}) cannot be represented in JSON.",
+                    value.to_type()
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_string(s: &str, out: &mut String) {
+        out.push('"');
+
+        s.chars().for_each(|c| match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        });
+
+        out.push('"');
+    }
+}
+
+struct JsonReader<'a> {
+    source: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl JsonReader<'_> {
+    fn value(&mut self) -> Result<LiteralValue, String> {
+        self.skip_whitespace();
+
+        match self.chars.peek().copied() {
+            Some((_, '[')) => self.list(),
+            Some((_, '{')) => self.object(),
+            Some((_, '"')) => Ok(LiteralValue::StringValue(self.string()?)),
+            Some((_, '-' | '0'..='9')) => self.number(),
+            Some((position, 't')) => self.keyword(position, "true", LiteralValue::True),
+            Some((position, 'f')) => self.keyword(position, "false", LiteralValue::False),
+            Some((position, 'n')) => self.keyword(position, "null", LiteralValue::Null),
+            Some((position, c)) => {
+                Err(self.error(position, &format!("unexpected character '{}'", c)))
+            }
+            None => Err(self.error(self.source.len(), "unexpected end of input")),
+        }
+    }
+
+    fn list(&mut self) -> Result<LiteralValue, String> {
+        self.chars.next();
+
+        let mut elements: Vec<LiteralValue> = Vec::new();
+
+        self.skip_whitespace();
+
+        if self.eat(']') {
+            return Ok(LiteralValue::List(elements));
+        }
+
+        loop {
+            elements.push(self.value()?);
+
+            self.skip_whitespace();
+
+            if self.eat(']') {
+                return Ok(LiteralValue::List(elements));
+            }
+
+            self.expect(',')?;
+        }
+    }
+
+    fn object(&mut self) -> Result<LiteralValue, String> {
+        self.chars.next();
+
+        let mut pairs: Vec<LiteralValue> = Vec::new();
+
+        self.skip_whitespace();
+
+        if self.eat('}') {
+            return Ok(LiteralValue::List(pairs));
+        }
+
+        loop {
+            self.skip_whitespace();
+
+            match self.chars.peek().copied() {
+                Some((_, '"')) => {}
+                Some((position, _)) => return Err(self.error(position, "expected a string key")),
+                None => return Err(self.error(self.source.len(), "unexpected end of input")),
+            }
+
+            let key: String = self.string()?;
+
+            self.skip_whitespace();
+            self.expect(':')?;
+
+            let value: LiteralValue = self.value()?;
+
+            pairs.push(LiteralValue::List(vec![
+                LiteralValue::StringValue(key),
+                value,
+            ]));
+
+            self.skip_whitespace();
+
+            if self.eat('}') {
+                return Ok(LiteralValue::List(pairs));
+            }
+
+            self.expect(',')?;
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let (start, _) = self.chars.next().unwrap_or_default();
+        let mut s: String = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(s),
+                Some((position, '\\')) => match self.chars.next() {
+                    Some((_, '"')) => s.push('"'),
+                    Some((_, '\\')) => s.push('\\'),
+                    Some((_, '/')) => s.push('/'),
+                    Some((_, 'n')) => s.push('\n'),
+                    Some((_, 'r')) => s.push('\r'),
+                    Some((_, 't')) => s.push('\t'),
+                    Some((_, 'b')) => s.push('\u{8}'),
+                    Some((_, 'f')) => s.push('\u{c}'),
+                    Some((_, 'u')) => s.push(self.unicode_escape(position)?),
+                    _ => return Err(self.error(position, "invalid escape sequence")),
+                },
+                Some((position, c)) if (c as u32) < 0x20 => {
+                    return Err(self.error(position, "control character in string"))
+                }
+                Some((_, c)) => s.push(c),
+                None => return Err(self.error(start, "unterminated string")),
+            }
+        }
+    }
+
+    fn unicode_escape(&mut self, position: usize) -> Result<char, String> {
+        let high: u32 = self.hex4(position)?;
+
+        if (0xd800..0xdc00).contains(&high) {
+            if !(self.eat('\\') && self.eat('u')) {
+                return Err(self.error(position, "unpaired surrogate in unicode escape"));
+            }
+
+            let low: u32 = self.hex4(position)?;
+
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(self.error(position, "unpaired surrogate in unicode escape"));
+            }
+
+            return char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+                .ok_or_else(|| self.error(position, "invalid unicode escape"));
+        }
+
+        char::from_u32(high)
+            .ok_or_else(|| self.error(position, "unpaired surrogate in unicode escape"))
+    }
+
+    fn hex4(&mut self, position: usize) -> Result<u32, String> {
+        let mut code: u32 = 0;
+
+        for _ in 0..4 {
+            match self.chars.next().and_then(|(_, c)| c.to_digit(16)) {
+                Some(digit) => code = code * 16 + digit,
+                None => return Err(self.error(position, "invalid unicode escape")),
+            }
+        }
+
+        Ok(code)
+    }
+
+    fn number(&mut self) -> Result<LiteralValue, String> {
+        let (start, _) = self.chars.peek().copied().unwrap_or_default();
+        let mut end: usize = start;
+
+        while let Some((position, c)) = self.chars.peek().copied() {
+            if !matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9') {
+                break;
+            }
+
+            end = position + 1;
+            self.chars.next();
+        }
+
+        let text: &str = &self.source[start..end];
+
+        if !Self::is_number(text) {
+            return Err(self.error(start, &format!("invalid number '{}'", text)));
+        }
+
+        match text.parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(LiteralValue::Number(n)),
+            Ok(_) => Err(self.error(start, &format!("number '{}' is out of range", text))),
+            Err(_) => Err(self.error(start, &format!("invalid number '{}'", text))),
+        }
+    }
+
+    // -?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?
+    fn is_number(text: &str) -> bool {
+        let bytes: &[u8] = text.strip_prefix('-').unwrap_or(text).as_bytes();
+        let digits = |from: usize| -> usize {
+            from + bytes[from..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count()
+        };
+
+        let mut i: usize = match bytes.first() {
+            Some(b'0') => 1,
+            Some(b'1'..=b'9') => digits(1),
+            _ => return false,
+        };
+
+        if bytes.get(i) == Some(&b'.') {
+            let end: usize = digits(i + 1);
+
+            if end == i + 1 {
+                return false;
+            }
+
+            i = end;
+        }
+
+        if matches!(bytes.get(i), Some(b'e' | b'E')) {
+            i += 1;
+
+            if matches!(bytes.get(i), Some(b'+' | b'-')) {
+                i += 1;
+            }
+
+            let end: usize = digits(i);
+
+            if end == i {
+                return false;
+            }
+
+            i = end;
+        }
+
+        i == bytes.len()
+    }
+
+    fn keyword(
+        &mut self,
+        position: usize,
+        word: &str,
+        value: LiteralValue,
+    ) -> Result<LiteralValue, String> {
+        if !self.source[position..].starts_with(word) {
+            return Err(self.error(position, "invalid literal"));
+        }
+
+        word.chars().for_each(|_| {
+            self.chars.next();
+        });
+
+        Ok(value)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .next_if(|(_, c)| matches!(c, ' ' | '\t' | '\n' | '\r'))
+            .is_some()
+        {}
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.chars.next_if(|(_, c)| *c == expected).is_some()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+
+        if self.eat(expected) {
+            return Ok(());
+        }
+
+        let position: usize = self
+            .chars
+            .peek()
+            .map(|(position, _)| *position)
+            .unwrap_or(self.source.len());
+
+        Err(self.error(position, &format!("expected '{}'", expected)))
+    }
+
+    fn error(&self, position: usize, message: &str) -> String {
+        format!(
+            "(json::parse()) Invalid JSON at position {}: {}.",
+            position, message
+        )
+    }
+}
@@ -3,8 +3,8 @@ use std::{collections::HashMap, rc::Rc};
 pub struct List;
 
 use super::super::{
-    expr::{LiteralValue, NativeFunctionImpl},
-    panic::PanicHandler,
+    constants::NYX_MAX_LIST_LENGTH,
+    expr::{allocate, LiteralValue, NativeFunctionImpl},
 };
 
 impl List {
@@ -23,7 +23,7 @@ impl List {
             "gen",
             NativeFunctionImpl {
                 name: "gen",
-                fc: Rc::new(Self::gen),
+                fc: Rc::new(|args| Ok(Self::gen(args))),
             },
         );
 
@@ -67,6 +67,126 @@ impl List {
             },
         );
 
+        methods.insert(
+            "windows",
+            NativeFunctionImpl {
+                name: "windows",
+                fc: Rc::new(Self::windows),
+            },
+        );
+
+        methods.insert(
+            "map",
+            NativeFunctionImpl {
+                name: "map",
+                fc: Rc::new(Self::map),
+            },
+        );
+
+        methods.insert(
+            "filter",
+            NativeFunctionImpl {
+                name: "filter",
+                fc: Rc::new(Self::filter),
+            },
+        );
+
+        methods.insert(
+            "reduce",
+            NativeFunctionImpl {
+                name: "reduce",
+                fc: Rc::new(Self::reduce),
+            },
+        );
+
+        methods.insert(
+            "join_deep",
+            NativeFunctionImpl {
+                name: "join_deep",
+                fc: Rc::new(Self::join_deep),
+            },
+        );
+
+        methods.insert(
+            "sort",
+            NativeFunctionImpl {
+                name: "sort",
+                fc: Rc::new(Self::sort),
+            },
+        );
+
+        methods.insert(
+            "join",
+            NativeFunctionImpl {
+                name: "join",
+                fc: Rc::new(Self::join),
+            },
+        );
+
+        methods.insert(
+            "frequencies",
+            NativeFunctionImpl {
+                name: "frequencies",
+                fc: Rc::new(Self::frequencies),
+            },
+        );
+
+        methods.insert(
+            "insert",
+            NativeFunctionImpl {
+                name: "insert",
+                fc: Rc::new(Self::insert),
+            },
+        );
+
+        methods.insert(
+            "range",
+            NativeFunctionImpl {
+                name: "range",
+                fc: Rc::new(Self::range),
+            },
+        );
+
+        methods.insert(
+            "scan",
+            NativeFunctionImpl {
+                name: "scan",
+                fc: Rc::new(Self::scan),
+            },
+        );
+
+        methods.insert(
+            "max_index",
+            NativeFunctionImpl {
+                name: "max_index",
+                fc: Rc::new(Self::max_index),
+            },
+        );
+
+        methods.insert(
+            "min_index",
+            NativeFunctionImpl {
+                name: "min_index",
+                fc: Rc::new(Self::min_index),
+            },
+        );
+
+        methods.insert(
+            "flatten",
+            NativeFunctionImpl {
+                name: "flatten",
+                fc: Rc::new(Self::flatten),
+            },
+        );
+
+        methods.insert(
+            "concat",
+            NativeFunctionImpl {
+                name: "concat",
+                fc: Rc::new(Self::concat),
+            },
+        );
+
         methods
     }
 
@@ -74,239 +194,504 @@ impl List {
         LiteralValue::List(Vec::new())
     }
 
-    pub fn add(args: &[LiteralValue]) -> LiteralValue {
+    pub fn add(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() < 2 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(list::add()) Should must have 2 arguments or more.",
-            )
-            .panic();
-
-            return LiteralValue::Null;
+            return Err("(list::add()) Should must have 2 arguments or more.".to_string());
         }
 
         match &args[0] {
             LiteralValue::List(array) => {
                 let mut new: Vec<LiteralValue> = array.to_owned();
                 args.iter().skip(1).for_each(|i| new.push(i.to_owned()));
-                LiteralValue::List(new)
-            }
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(list::add()) First argument must be an list.",
-                )
-                .panic();
-
-                LiteralValue::Null
+                Ok(LiteralValue::List(new))
             }
+            _ => Err("(list::add()) First argument must be an list.".to_string()),
         }
     }
 
-    pub fn size(args: &[LiteralValue]) -> LiteralValue {
+    pub fn size(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.is_empty() {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(list::size()) Should must have 1 arguments.",
-            )
-            .panic();
-
-            return LiteralValue::Null;
+            return Err("(list::size()) Should must have 1 arguments.".to_string());
         }
 
         match &args[0] {
-            LiteralValue::List(list) => LiteralValue::Number(list.len() as f64),
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(list::size()) First argument must be an list.",
-                )
-                .panic();
-
-                LiteralValue::Null
-            }
+            LiteralValue::List(list) => Ok(LiteralValue::Number(list.len() as f64)),
+            _ => Err("(list::size()) First argument must be an list.".to_string()),
         }
     }
 
-    pub fn reverse(args: &[LiteralValue]) -> LiteralValue {
+    pub fn reverse(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 1 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(list::reverse()) Should must have 1 arguments.",
-            )
-            .panic();
-
-            return LiteralValue::Null;
+            return Err("(list::reverse()) Should must have 1 arguments.".to_string());
         }
 
         match &args[0] {
             LiteralValue::List(list) => {
                 let mut new: Vec<LiteralValue> = list.clone();
                 new.reverse();
-                LiteralValue::List(new)
+                Ok(LiteralValue::List(new))
             }
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(list::reverse()) First argument must be an list.",
-                )
-                .panic();
-
-                LiteralValue::Null
+            _ => Err("(list::reverse()) First argument must be an list.".to_string()),
+        }
+    }
+
+    pub fn get(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(list::get()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::List(list), LiteralValue::Number(num)) => match Self::index(list, *num) {
+                Some(i) => Ok(list[i].to_owned()),
+                None => Err(
+                    "(list::get()) Index must be an integer between 0 and the size of the list minus 1."
+                        .to_string(),
+                ),
+            },
+
+            (_, _) => Err(
+                "(list::get()) First argument must be an list or the second argument must be a number."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn pop(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(list::pop()) Should must have 1 argument.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::List(list) => {
+                let mut new: Vec<LiteralValue> = list.to_owned();
+                let rs: Option<LiteralValue> = new.pop();
+
+                if rs.is_some() {
+                    return Ok(LiteralValue::List(new));
+                }
+
+                Ok(LiteralValue::Null)
             }
+            _ => Err("(list::pop()) First argument must be an list.".to_string()),
         }
     }
 
-    pub fn get(args: &[LiteralValue]) -> LiteralValue {
+    pub fn remove(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 2 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(list::get()) Should must have 2 arguments.",
-            )
-            .panic();
+            return Err("(list::remove()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::List(list), LiteralValue::Number(num)) => match Self::index(list, *num) {
+                Some(i) => Ok(list[i].to_owned()),
+                None => Err(
+                    "(list::remove()) Index must be an integer between 0 and the size of the list minus 1."
+                        .to_string(),
+                ),
+            },
+
+            (_, _) => Err("(list::remove()) First argument must be an list.".to_string()),
+        }
+    }
 
-            return LiteralValue::Null;
+    pub fn windows(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(list::windows()) Should must have 2 arguments.".to_string());
         }
 
         match (&args[0], &args[1]) {
-            (LiteralValue::List(list), LiteralValue::Number(num)) => {
-                if *num != 0.0 {
-                    if let Some(i) = list.get(*num as usize - 1) {
-                        return LiteralValue::List(vec![i.to_owned(), LiteralValue::Number(*num)]);
-                    } else {
-                        PanicHandler::new(
-                            None,
-                            None,
-                            None,
-                            "(list::get()) Index must be less than the size of the list.",
-                        )
-                        .panic();
-
-                        return LiteralValue::Null;
+            (LiteralValue::List(list), LiteralValue::Number(size)) => {
+                if *size < 1.0 || size.fract() != 0.0 || *size as usize > list.len() {
+                    return Ok(LiteralValue::List(Vec::new()));
+                }
+
+                allocate((list.len() - *size as usize + 1) * *size as usize)?;
+
+                Ok(LiteralValue::List(
+                    list.windows(*size as usize)
+                        .map(|window| LiteralValue::List(window.to_vec()))
+                        .collect(),
+                ))
+            }
+
+            (_, _) => Err(
+                "(list::windows()) First argument must be an list or the second argument must be a number."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn map(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(list::map()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::List(list), LiteralValue::Callable(fc)) => {
+                let mut new: Vec<LiteralValue> = Vec::with_capacity(list.len());
+
+                for i in list {
+                    new.push(fc.call(&[i.to_owned()])?);
+                }
+
+                Ok(LiteralValue::List(new))
+            }
+            (_, _) => Err(
+                "(list::map()) The correctly arguments are (source list, mapper callable)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn filter(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(list::filter()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::List(list), LiteralValue::Callable(fc)) => {
+                let mut new: Vec<LiteralValue> = Vec::new();
+
+                for i in list {
+                    if fc.call(&[i.to_owned()])?.truthy()? == LiteralValue::True {
+                        new.push(i.to_owned());
                     }
                 }
 
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(list::get()) Index must be greater than 0.",
-                )
-                .panic();
+                Ok(LiteralValue::List(new))
+            }
+            (_, _) => Err(
+                "(list::filter()) The correctly arguments are (source list, predicate callable)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn reduce(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 3 {
+            return Err("(list::reduce()) Should must have 3 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::List(list), LiteralValue::Callable(fc)) => {
+                let mut acc: LiteralValue = args[2].to_owned();
 
-                LiteralValue::Null
+                for i in list {
+                    acc = fc.call(&[acc, i.to_owned()])?;
+                }
+
+                Ok(acc)
             }
+            (_, _) => Err(
+                "(list::reduce()) The correctly arguments are (source list, reducer callable, initial value)."
+                    .to_string(),
+            ),
+        }
+    }
 
-            (_, _) => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(list::get()) First argument must be an list or the second argument must be a number.",
-                )
-                .panic();
+    pub fn join_deep(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(list::join_deep()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::List(list), LiteralValue::StringValue(sep)) => {
+                let mut leaves: Vec<String> = Vec::new();
+                Self::collect_leaves(list, &mut leaves);
 
-                LiteralValue::Null
+                Ok(LiteralValue::StringValue(leaves.join(sep)))
             }
+
+            (_, _) => Err(
+                "(list::join_deep()) First argument must be an list or the second argument must be a string."
+                    .to_string(),
+            ),
         }
     }
 
-    pub fn pop(args: &[LiteralValue]) -> LiteralValue {
-        if args.len() != 1 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(list::pop()) Should must have 1 argument.",
-            )
-            .panic();
+    fn collect_leaves(list: &[LiteralValue], leaves: &mut Vec<String>) {
+        list.iter().for_each(|i| match i {
+            LiteralValue::List(nested) => Self::collect_leaves(nested, leaves),
+            _ => leaves.push(i.convert()),
+        });
+    }
 
-            return LiteralValue::Null;
+    pub fn sort(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(list::sort()) Should must have 1 arguments.".to_string());
         }
 
         match &args[0] {
             LiteralValue::List(list) => {
                 let mut new: Vec<LiteralValue> = list.to_owned();
-                let rs: Option<LiteralValue> = new.pop();
 
-                if rs.is_some() {
-                    return LiteralValue::List(new);
+                if new.iter().all(|i| matches!(i, LiteralValue::Number(_))) {
+                    new.sort_by(|a, b| match (a, b) {
+                        (LiteralValue::Number(x), LiteralValue::Number(y)) => x.total_cmp(y),
+                        _ => unreachable!(),
+                    });
+                } else if new
+                    .iter()
+                    .all(|i| matches!(i, LiteralValue::StringValue(_)))
+                {
+                    new.sort_by(|a, b| match (a, b) {
+                        (LiteralValue::StringValue(x), LiteralValue::StringValue(y)) => x.cmp(y),
+                        _ => unreachable!(),
+                    });
+                } else {
+                    return Err(
+                        "(list::sort()) All elements must be numbers or all elements must be strings."
+                            .to_string(),
+                    );
                 }
 
-                LiteralValue::Null
-            }
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(list::pop()) First argument must be an list.",
-                )
-                .panic();
-
-                LiteralValue::Null
+                Ok(LiteralValue::List(new))
             }
+            _ => Err("(list::sort()) First argument must be an list.".to_string()),
         }
     }
 
-    pub fn remove(args: &[LiteralValue]) -> LiteralValue {
+    pub fn join(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 2 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(list::remove()) Should must have 2 arguments.",
-            )
-            .panic();
+            return Err("(list::join()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::List(list), LiteralValue::StringValue(sep)) => {
+                Ok(LiteralValue::StringValue(
+                    list.iter()
+                        .map(|i| i.convert())
+                        .collect::<Vec<String>>()
+                        .join(sep),
+                ))
+            }
 
-            return LiteralValue::Null;
+            (_, _) => Err(
+                "(list::join()) First argument must be an list or the second argument must be a string."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn frequencies(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(list::frequencies()) Should must have 1 arguments.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::List(list) => {
+                let mut counts: Vec<(LiteralValue, usize)> = Vec::new();
+
+                list.iter()
+                    .for_each(|i| match counts.iter_mut().find(|(value, _)| value == i) {
+                        Some((_, count)) => *count += 1,
+                        None => counts.push((i.to_owned(), 1)),
+                    });
+
+                Ok(LiteralValue::List(
+                    counts
+                        .into_iter()
+                        .map(|(value, count)| {
+                            LiteralValue::List(vec![value, LiteralValue::Number(count as f64)])
+                        })
+                        .collect(),
+                ))
+            }
+            _ => Err("(list::frequencies()) First argument must be an list.".to_string()),
+        }
+    }
+
+    pub fn insert(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 3 {
+            return Err("(list::insert()) Should must have 3 arguments.".to_string());
         }
 
         match (&args[0], &args[1]) {
             (LiteralValue::List(list), LiteralValue::Number(num)) => {
+                if *num < 0.0 || num.fract() != 0.0 || *num as usize > list.len() {
+                    return Err(
+                        "(list::insert()) Index must be an integer between 0 and the size of the list."
+                            .to_string(),
+                    );
+                }
+
                 let mut new: Vec<LiteralValue> = list.to_owned();
+                new.insert(*num as usize, args[2].to_owned());
+
+                Ok(LiteralValue::List(new))
+            }
+
+            (_, _) => Err(
+                "(list::insert()) First argument must be an list or the second argument must be a number."
+                    .to_string(),
+            ),
+        }
+    }
 
-                if new.get(*num as usize - 1).is_some() {
-                    let rs: LiteralValue = new.remove(*num as usize - 1);
-                    return rs;
+    fn index(list: &[LiteralValue], num: f64) -> Option<usize> {
+        if num < 0.0 || num.fract() != 0.0 || num as usize >= list.len() {
+            return None;
+        }
+
+        Some(num as usize)
+    }
+
+    pub fn range(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 && args.len() != 3 {
+            return Err("(list::range()) Should must have 2 or 3 arguments.".to_string());
+        }
+
+        let step: LiteralValue = args.get(2).cloned().unwrap_or(LiteralValue::Number(1.0));
+
+        match (&args[0], &args[1], &step) {
+            (
+                LiteralValue::Number(start),
+                LiteralValue::Number(end),
+                LiteralValue::Number(step),
+            ) => {
+                if *step <= 0.0 || !step.is_finite() {
+                    return Err(
+                        "(list::range()) The step must be a number greater than 0.".to_string()
+                    );
+                }
+
+                let count: f64 = ((end - start) / step).ceil();
+
+                if count.is_nan() || count > NYX_MAX_LIST_LENGTH as f64 {
+                    return Err(format!(
+                        "(list::range()) The result would exceed the maximum list length ({} elements).",
+                        NYX_MAX_LIST_LENGTH
+                    ));
                 }
 
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(list::remove()) Index must be less than the size of the list.",
-                )
-                .panic();
+                allocate(count.max(0.0) as usize)?;
+
+                let mut new: Vec<LiteralValue> = Vec::with_capacity(count.max(0.0) as usize);
+                let mut i: usize = 0;
+
+                loop {
+                    let value: f64 = start + step * i as f64;
+
+                    if value >= *end {
+                        break;
+                    }
+
+                    new.push(LiteralValue::Number(value));
+                    i += 1;
+                }
 
-                LiteralValue::Null
+                Ok(LiteralValue::List(new))
             }
+            _ => {
+                Err("(list::range()) Should must have 2 or 3 arguments of type number.".to_string())
+            }
+        }
+    }
+
+    pub fn scan(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 3 {
+            return Err("(list::scan()) Should must have 3 arguments.".to_string());
+        }
+
+        match (&args[0], &args[2]) {
+            (LiteralValue::List(list), LiteralValue::Callable(fc)) => {
+                let mut acc: LiteralValue = args[1].to_owned();
+                let mut new: Vec<LiteralValue> = Vec::with_capacity(list.len());
+
+                for i in list {
+                    acc = fc.call(&[acc, i.to_owned()])?;
+                    new.push(acc.to_owned());
+                }
 
-            (_, _) => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(list::remove()) First argument must be an list.",
-                )
-                .panic();
+                Ok(LiteralValue::List(new))
+            }
+            (_, _) => Err(
+                "(list::scan()) The correctly arguments are (source list, initial value, reducer callable)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn max_index(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::extreme_index("max_index", args, |candidate, best| candidate > best)
+    }
+
+    pub fn min_index(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::extreme_index("min_index", args, |candidate, best| candidate < best)
+    }
+
+    pub fn flatten(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(list::flatten()) Should must have 1 arguments.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::List(list) => {
+                let mut flat: Vec<LiteralValue> = Vec::with_capacity(list.len());
+
+                list.iter().for_each(|element| match element {
+                    LiteralValue::List(inner) => flat.extend(inner.iter().cloned()),
+                    _ => flat.push(element.to_owned()),
+                });
+
+                Ok(LiteralValue::List(flat))
+            }
+            _ => Err("(list::flatten()) First argument must be an list.".to_string()),
+        }
+    }
+
+    pub fn concat(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(list::concat()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::List(first), LiteralValue::List(second)) => Ok(LiteralValue::List(
+                first.iter().chain(second.iter()).cloned().collect(),
+            )),
+            _ => Err(
+                "(list::concat()) The correctly arguments are (first list, second list)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    fn extreme_index(
+        name: &str,
+        args: &[LiteralValue],
+        better: fn(f64, f64) -> bool,
+    ) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err(format!("(list::{}()) Should must have 1 arguments.", name));
+        }
+
+        match &args[0] {
+            LiteralValue::List(list) => {
+                if list.is_empty() {
+                    return Err(format!("(list::{}()) The list must not be empty.", name));
+                }
+
+                let mut best: Option<(usize, f64)> = None;
+
+                for (index, element) in list.iter().enumerate() {
+                    let LiteralValue::Number(n) = element else {
+                        return Err(format!("(list::{}()) All elements must be numbers.", name));
+                    };
+
+                    match best {
+                        Some((_, value)) if !better(*n, value) => {}
+                        _ => best = Some((index, *n)),
+                    }
+                }
 
-                LiteralValue::Null
+                Ok(LiteralValue::Number(
+                    best.map(|(index, _)| index).unwrap_or_default() as f64,
+                ))
             }
+            _ => Err(format!(
+                "(list::{}()) First argument must be an list.",
+                name
+            )),
         }
     }
 }
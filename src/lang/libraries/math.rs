@@ -1,8 +1,8 @@
 use std::{collections::HashMap, rc::Rc};
 
 use super::super::{
+    constants::{NYX_DEFAULT_EPSILON, NYX_MAX_SAFE_INTEGER},
     expr::{LiteralValue, NativeFunctionImpl},
-    panic::PanicHandler,
 };
 
 pub struct Math;
@@ -27,6 +27,54 @@ impl Math {
             },
         );
 
+        methods.insert(
+            "is_infinite",
+            NativeFunctionImpl {
+                name: "is_infinite",
+                fc: Rc::new(Self::is_infinite),
+            },
+        );
+
+        methods.insert(
+            "approx_eq",
+            NativeFunctionImpl {
+                name: "approx_eq",
+                fc: Rc::new(Self::approx_eq),
+            },
+        );
+
+        methods.insert(
+            "round_half_even",
+            NativeFunctionImpl {
+                name: "round_half_even",
+                fc: Rc::new(Self::round_half_even),
+            },
+        );
+
+        methods.insert(
+            "mod_pow",
+            NativeFunctionImpl {
+                name: "mod_pow",
+                fc: Rc::new(Self::mod_pow),
+            },
+        );
+
+        methods.insert(
+            "is_nan",
+            NativeFunctionImpl {
+                name: "is_nan",
+                fc: Rc::new(Self::is_nan),
+            },
+        );
+
+        methods.insert(
+            "is_finite",
+            NativeFunctionImpl {
+                name: "is_finite",
+                fc: Rc::new(Self::is_finite),
+            },
+        );
+
         methods
     }
 
@@ -36,99 +84,176 @@ impl Math {
         constants.insert("PI", LiteralValue::Number(std::f64::consts::PI));
         constants.insert("E", LiteralValue::Number(std::f64::consts::E));
         constants.insert("TAU", LiteralValue::Number(std::f64::consts::TAU));
+        constants.insert("SQRT2", LiteralValue::Number(std::f64::consts::SQRT_2));
+        constants.insert("LN2", LiteralValue::Number(std::f64::consts::LN_2));
+        constants.insert("LN10", LiteralValue::Number(std::f64::consts::LN_10));
+        constants.insert("INFINITY", LiteralValue::Number(f64::INFINITY));
+        constants.insert("NEG_INFINITY", LiteralValue::Number(f64::NEG_INFINITY));
 
         constants
     }
 
-    pub fn sqrt(args: &[LiteralValue]) -> LiteralValue {
+    pub fn sqrt(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 1 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(math::sqrt()) Should must have 1 arguments.",
-            )
-            .panic();
-
-            return LiteralValue::Null;
+            return Err("(math::sqrt()) Should must have 1 arguments.".to_string());
         }
 
         match args[0] {
             LiteralValue::Number(i) => {
                 if i < 0.0 {
-                    PanicHandler::new(
-                        None,
-                        None,
-                        None,
-                        "(math::sqrt()) Should must have 1 argument of type number greater than 0.",
-                    )
-                    .panic();
-
-                    return LiteralValue::Null;
+                    return Err(
+                        "(math::sqrt()) Should must have 1 argument of type number greater than 0."
+                            .to_string(),
+                    );
                 }
 
-                LiteralValue::Number(i.sqrt())
-            }
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(math::sqrt()) Should must have 1 argument of type number.",
-                )
-                .panic();
-
-                LiteralValue::Null
+                Ok(LiteralValue::Number(i.sqrt()))
             }
+            _ => Err("(math::sqrt()) Should must have 1 argument of type number.".to_string()),
         }
     }
 
-    pub fn pow(args: &[LiteralValue]) -> LiteralValue {
+    /// Any exponent is allowed, including negative and fractional ones. Results
+    /// that overflow or are not a number (like `pow(0, -1)`) raise an error.
+    pub fn pow(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 2 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(math::pow()) Should must have 2 arguments.",
-            )
-            .panic();
-
-            return LiteralValue::Null;
+            return Err("(math::pow()) Should must have 2 arguments.".to_string());
         }
 
         match (&args[0], &args[1]) {
             (LiteralValue::Number(x), LiteralValue::Number(y)) => {
-                if *y < 0.0 {
-                    PanicHandler::new(
-                        None,
-                        None,
-                        None,
-                        "(math::pow()) Should must have 2 arguments of type number greater than 0.",
-                    )
-                    .panic();
-
-                    return LiteralValue::Null;
+                let rs: f64 = x.powf(*y);
+
+                if !rs.is_finite() {
+                    return Err(format!(
+                        "(math::pow()) The result of ({}) to the power of ({}) is not a finite number.",
+                        x, y
+                    ));
                 }
 
-                let rs: f64 = x.powf(*y);
+                Ok(LiteralValue::Number(rs))
+            }
+            _ => Err("(math::pow()) Should must have 2 arguments of type number.".to_string()),
+        }
+    }
 
-                if rs.is_infinite() {
-                    return LiteralValue::StringValue("infinite".to_string());
+    pub fn is_infinite(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::is_class("is_infinite", args, f64::is_infinite)
+    }
+
+    pub fn is_nan(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::is_class("is_nan", args, f64::is_nan)
+    }
+
+    pub fn is_finite(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::is_class("is_finite", args, f64::is_finite)
+    }
+
+    fn is_class(
+        name: &str,
+        args: &[LiteralValue],
+        check: fn(f64) -> bool,
+    ) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err(format!("(math::{}()) Should must have 1 arguments.", name));
+        }
+
+        match args[0] {
+            LiteralValue::Number(i) if check(i) => Ok(LiteralValue::True),
+            LiteralValue::Number(_) => Ok(LiteralValue::False),
+            _ => Err(format!(
+                "(math::{}()) Should must have 1 argument of type number.",
+                name
+            )),
+        }
+    }
+
+    pub fn approx_eq(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 && args.len() != 3 {
+            return Err("(math::approx_eq()) Should must have 2 or 3 arguments.".to_string());
+        }
+
+        let epsilon: LiteralValue = args
+            .get(2)
+            .cloned()
+            .unwrap_or(LiteralValue::Number(NYX_DEFAULT_EPSILON));
+
+        match (&args[0], &args[1], &epsilon) {
+            (LiteralValue::Number(a), LiteralValue::Number(b), LiteralValue::Number(epsilon)) => {
+                if *epsilon < 0.0 {
+                    return Err(
+                        "(math::approx_eq()) The epsilon must be greater than or equal to 0."
+                            .to_string(),
+                    );
                 }
 
-                LiteralValue::Number(rs)
+                if (a - b).abs() <= *epsilon {
+                    return Ok(LiteralValue::True);
+                }
+
+                Ok(LiteralValue::False)
             }
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(math::pow()) Should must have 2 arguments of type number.",
-                )
-                .panic();
-
-                LiteralValue::Null
+            _ => Err(
+                "(math::approx_eq()) Should must have 2 or 3 arguments of type number.".to_string(),
+            ),
+        }
+    }
+
+    pub fn round_half_even(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(math::round_half_even()) Should must have 1 arguments.".to_string());
+        }
+
+        match args[0] {
+            LiteralValue::Number(i) => Ok(LiteralValue::Number(i.round_ties_even())),
+            _ => Err(
+                "(math::round_half_even()) Should must have 1 argument of type number.".to_string(),
+            ),
+        }
+    }
+
+    pub fn mod_pow(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 3 {
+            return Err("(math::mod_pow()) Should must have 3 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1], &args[2]) {
+            (
+                LiteralValue::Number(base),
+                LiteralValue::Number(exp),
+                LiteralValue::Number(modulus),
+            ) => {
+                if [base, exp, modulus]
+                    .iter()
+                    .any(|n| **n < 0.0 || **n > NYX_MAX_SAFE_INTEGER || n.fract() != 0.0)
+                {
+                    return Err(
+                        "(math::mod_pow()) All arguments must be integers between 0 and 9007199254740991."
+                            .to_string(),
+                    );
+                }
+
+                if *modulus == 0.0 {
+                    return Err("(math::mod_pow()) The modulus must be greater than 0.".to_string());
+                }
+
+                let modulus: i128 = *modulus as i128;
+                let mut base: i128 = *base as i128 % modulus;
+                let mut exp: i128 = *exp as i128;
+                let mut result: i128 = 1 % modulus;
+
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        result = result * base % modulus;
+                    }
+
+                    base = base * base % modulus;
+                    exp >>= 1;
+                }
+
+                Ok(LiteralValue::Number(result as f64))
             }
+            _ => Err("(math::mod_pow()) Should must have 3 arguments of type number.".to_string()),
         }
     }
 }
@@ -1,5 +1,10 @@
+pub mod builtins;
+pub mod color;
+pub mod fs;
+pub mod json;
 pub mod list;
 pub mod math;
 pub mod os;
 pub mod strings;
+pub mod time;
 pub mod utils;
@@ -1,15 +1,17 @@
 use std::{
     collections::HashMap,
+    env::{current_dir, set_current_dir, var},
     io::{stdin, stdout, Write},
+    path::{Path, PathBuf},
     process::exit,
     rc::Rc,
-    time::UNIX_EPOCH,
+    thread::sleep,
+    time::{Duration, UNIX_EPOCH},
 };
 
-use super::super::{
-    expr::{LiteralValue, NativeFunctionImpl},
-    panic::PanicHandler,
-};
+use terminal_size::{Height, Width};
+
+use super::super::expr::{LiteralValue, NativeFunctionImpl};
 
 pub struct OS;
 
@@ -41,6 +43,54 @@ impl OS {
             },
         );
 
+        methods.insert(
+            "terminal_size",
+            NativeFunctionImpl {
+                name: "terminal_size",
+                fc: Rc::new(|args| Ok(Self::terminal_size(args))),
+            },
+        );
+
+        methods.insert(
+            "getcwd",
+            NativeFunctionImpl {
+                name: "getcwd",
+                fc: Rc::new(Self::getcwd),
+            },
+        );
+
+        methods.insert(
+            "chdir",
+            NativeFunctionImpl {
+                name: "chdir",
+                fc: Rc::new(Self::chdir),
+            },
+        );
+
+        methods.insert(
+            "sleep",
+            NativeFunctionImpl {
+                name: "sleep",
+                fc: Rc::new(Self::sleep),
+            },
+        );
+
+        methods.insert(
+            "home_dir",
+            NativeFunctionImpl {
+                name: "home_dir",
+                fc: Rc::new(Self::home_dir),
+            },
+        );
+
+        methods.insert(
+            "join_path",
+            NativeFunctionImpl {
+                name: "join_path",
+                fc: Rc::new(Self::join_path),
+            },
+        );
+
         methods
     }
 
@@ -60,61 +110,41 @@ impl OS {
         constants
     }
 
-    pub fn exit(args: &[LiteralValue]) -> LiteralValue {
+    pub fn exit(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::exit_with("os::exit", args)
+    }
+
+    pub fn exit_with(name: &str, args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 1 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(os::exit()) Should must have 1 argument.",
-            )
-            .panic();
-
-            return LiteralValue::Null;
+            return Err(format!("({}()) Should must have 1 argument.", name));
         }
 
         match args[0] {
-            LiteralValue::Number(i) => {
-                if (i as i32) > 0 {
-                    panic!("\nNyx exit with code ({}).\n", i);
-                }
-
-                exit(i as i32);
-            }
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(os::exit()) Should must have 1 argument of type number.",
-                )
-                .panic();
-
-                LiteralValue::Null
+            LiteralValue::Number(code)
+                if code.fract() == 0.0 && code >= i32::MIN as f64 && code <= i32::MAX as f64 =>
+            {
+                stdout().flush().ok();
+                exit(code as i32)
             }
+            _ => Err(format!(
+                "({}()) Should must have 1 argument of type integer.",
+                name
+            )),
         }
     }
 
-    pub fn current_time(_args: &[LiteralValue]) -> LiteralValue {
+    pub fn current_time(_args: &[LiteralValue]) -> Result<LiteralValue, String> {
         let time: u128 = std::time::SystemTime::now()
             .duration_since(UNIX_EPOCH)
-            .expect("[INTERNAL ERROR] Could not get system time.")
+            .map_err(|_| "(os::current_time()) Could not get system time.".to_string())?
             .as_millis();
 
-        LiteralValue::Number(time as f64 / 1000.0)
+        Ok(LiteralValue::Number(time as f64 / 1000.0))
     }
 
-    pub fn input(args: &[LiteralValue]) -> LiteralValue {
+    pub fn input(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() >= 2 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(os::input()) Should must have 1 argument or less.",
-            )
-            .panic();
-
-            return LiteralValue::Null;
+            return Err("(os::input()) Should must have 1 argument or less.".to_string());
         }
 
         match args.len() {
@@ -126,26 +156,115 @@ impl OS {
                 stdout().flush().ok();
 
                 if stdin().read_line(&mut reader).is_ok() {
-                    return LiteralValue::StringValue(reader.trim().to_string());
+                    return Ok(LiteralValue::StringValue(reader.trim().to_string()));
                 }
 
-                PanicHandler::new(None, None, None, "(os::input()) had an unexpected error.")
-                    .panic();
-
-                LiteralValue::Null
+                Err("(os::input()) had an unexpected error.".to_string())
             }
             _ => {
                 let mut reader: String = String::new();
 
                 if stdin().read_line(&mut reader).is_ok() {
-                    return LiteralValue::StringValue(reader.trim().to_string());
+                    return Ok(LiteralValue::StringValue(reader.trim().to_string()));
+                }
+
+                Err("(os::input()) had an unexpected error.".to_string())
+            }
+        }
+    }
+
+    pub fn terminal_size(_args: &[LiteralValue]) -> LiteralValue {
+        match terminal_size::terminal_size() {
+            Some((Width(columns), Height(rows))) => LiteralValue::List(vec![
+                LiteralValue::Number(columns as f64),
+                LiteralValue::Number(rows as f64),
+            ]),
+            None => LiteralValue::Null,
+        }
+    }
+
+    pub fn getcwd(_args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        match current_dir() {
+            Ok(path) => Ok(LiteralValue::StringValue(
+                path.to_string_lossy().to_string(),
+            )),
+            Err(_) => Err("(os::getcwd()) The current directory is not accessible.".to_string()),
+        }
+    }
+
+    pub fn chdir(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(os::chdir()) Should must have 1 arguments.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(path) => {
+                if !Path::new(path).is_dir() {
+                    return Err("(os::chdir()) The path must be an existing directory.".to_string());
                 }
 
-                PanicHandler::new(None, None, None, "(os::input()) had an unexpected error.")
-                    .panic();
+                if set_current_dir(path).is_ok() {
+                    return Ok(LiteralValue::True);
+                }
 
-                LiteralValue::Null
+                Ok(LiteralValue::False)
             }
+            _ => Err("(os::chdir()) The correctly arguments are (path string).".to_string()),
         }
     }
+
+    pub fn sleep(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(os::sleep()) Should must have 1 arguments.".to_string());
+        }
+
+        match args[0] {
+            LiteralValue::Number(ms) if ms >= 0.0 && ms.is_finite() => {
+                sleep(Duration::from_millis(ms as u64));
+
+                Ok(LiteralValue::Null)
+            }
+            _ => Err(
+                "(os::sleep()) Should must have 1 argument of type number greater than or equal to 0."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn home_dir(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if !args.is_empty() {
+            return Err("(os::home_dir()) Should must have 0 arguments.".to_string());
+        }
+
+        ["HOME", "USERPROFILE"]
+            .iter()
+            .filter_map(|name| var(name).ok())
+            .find(|path| !path.is_empty())
+            .map(LiteralValue::StringValue)
+            .ok_or_else(|| "(os::home_dir()) The home directory could not be found.".to_string())
+    }
+
+    pub fn join_path(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() < 2 {
+            return Err("(os::join_path()) Should must have 2 or more arguments.".to_string());
+        }
+
+        let mut path: PathBuf = PathBuf::new();
+
+        for arg in args {
+            match arg {
+                LiteralValue::StringValue(part) => path.push(part),
+                _ => {
+                    return Err(
+                        "(os::join_path()) The correctly arguments are (string, string, ...)."
+                            .to_string(),
+                    )
+                }
+            }
+        }
+
+        Ok(LiteralValue::StringValue(
+            path.to_string_lossy().to_string(),
+        ))
+    }
 }
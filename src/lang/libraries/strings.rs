@@ -1,8 +1,8 @@
 use std::{collections::HashMap, rc::Rc};
 
 use super::super::{
-    expr::{LiteralValue, NativeFunctionImpl},
-    panic::PanicHandler,
+    constants::NYX_MAX_STRING_LENGTH,
+    expr::{allocate, LiteralValue, NativeFunctionImpl},
 };
 
 pub struct Strings;
@@ -75,6 +75,94 @@ impl Strings {
             },
         );
 
+        methods.insert(
+            "codepoints",
+            NativeFunctionImpl {
+                name: "codepoints",
+                fc: Rc::new(Self::codepoints),
+            },
+        );
+
+        methods.insert(
+            "contains",
+            NativeFunctionImpl {
+                name: "contains",
+                fc: Rc::new(Self::contains),
+            },
+        );
+
+        methods.insert(
+            "starts_with",
+            NativeFunctionImpl {
+                name: "starts_with",
+                fc: Rc::new(Self::starts_with),
+            },
+        );
+
+        methods.insert(
+            "ends_with",
+            NativeFunctionImpl {
+                name: "ends_with",
+                fc: Rc::new(Self::ends_with),
+            },
+        );
+
+        methods.insert(
+            "substring",
+            NativeFunctionImpl {
+                name: "substring",
+                fc: Rc::new(Self::substring),
+            },
+        );
+
+        methods.insert(
+            "repeat",
+            NativeFunctionImpl {
+                name: "repeat",
+                fc: Rc::new(Self::repeat),
+            },
+        );
+
+        methods.insert(
+            "visible_length",
+            NativeFunctionImpl {
+                name: "visible_length",
+                fc: Rc::new(Self::visible_length),
+            },
+        );
+
+        methods.insert(
+            "swap_case",
+            NativeFunctionImpl {
+                name: "swap_case",
+                fc: Rc::new(Self::swap_case),
+            },
+        );
+
+        methods.insert(
+            "expand_tabs",
+            NativeFunctionImpl {
+                name: "expand_tabs",
+                fc: Rc::new(Self::expand_tabs),
+            },
+        );
+
+        methods.insert(
+            "fit",
+            NativeFunctionImpl {
+                name: "fit",
+                fc: Rc::new(Self::fit),
+            },
+        );
+
+        methods.insert(
+            "char_at",
+            NativeFunctionImpl {
+                name: "char_at",
+                fc: Rc::new(Self::char_at),
+            },
+        );
+
         methods
     }
 
@@ -84,42 +172,20 @@ impl Strings {
         constants
     }
 
-    pub fn length(args: &[LiteralValue]) -> LiteralValue {
+    pub fn length(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.is_empty() {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(string::length()) Should must have 1 arguments.",
-            )
-            .panic();
+            return Err("(string::length()) Should must have 1 arguments.".to_string());
         }
 
         match &args[0] {
-            LiteralValue::StringValue(s) => LiteralValue::Number(s.len() as f64),
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(string::length()) First argument must be a string.",
-                )
-                .panic();
-
-                LiteralValue::Null
-            }
+            LiteralValue::StringValue(s) => Ok(LiteralValue::Number(s.len() as f64)),
+            _ => Err("(string::length()) First argument must be a string.".to_string()),
         }
     }
 
-    pub fn split(args: &[LiteralValue]) -> LiteralValue {
+    pub fn split(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 2 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(string::split()) Should must have 2 arguments.",
-            )
-            .panic();
+            return Err("(string::split()) Should must have 2 arguments.".to_string());
         }
 
         match (&args[0], &args[1]) {
@@ -130,31 +196,18 @@ impl Strings {
                     new_list.push(LiteralValue::StringValue(v.to_string()));
                 });
 
-                LiteralValue::List(new_list)
-            }
-            (_, _) => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(string::split()) The first argument must be a string and the other second argument must also be a string.",
-                )
-                .panic();
-
-                LiteralValue::Null
+                Ok(LiteralValue::List(new_list))
             }
+            (_, _) => Err(
+                "(string::split()) The first argument must be a string and the other second argument must also be a string."
+                    .to_string(),
+            ),
         }
     }
 
-    pub fn find(args: &[LiteralValue]) -> LiteralValue {
+    pub fn find(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 2 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(string::find()) Should must have 2 arguments.",
-            )
-            .panic();
+            return Err("(string::find()) Should must have 2 arguments.".to_string());
         }
 
         match (&args[0], &args[1]) {
@@ -162,65 +215,39 @@ impl Strings {
                 let rs: Option<usize> = s.find(search);
 
                 if let Some(r) = rs {
-                    return LiteralValue::Number(r as f64);
+                    return Ok(LiteralValue::Number(r as f64));
                 }
 
-                LiteralValue::Null
+                Ok(LiteralValue::Null)
             }
 
-            (_, _) => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(string::find()) The first argument must be a string and the other second argument must also be a string.",
-                )
-                .panic();
-
-                LiteralValue::Null
-            }
+            (_, _) => Err(
+                "(string::find()) The first argument must be a string and the other second argument must also be a string."
+                    .to_string(),
+            ),
         }
     }
 
-    pub fn push(args: &[LiteralValue]) -> LiteralValue {
+    pub fn push(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 2 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(string::push()) Should must have 2 arguments.",
-            )
-            .panic();
+            return Err("(string::push()) Should must have 2 arguments.".to_string());
         }
 
         match (args[0].clone(), args[1].clone()) {
             (LiteralValue::StringValue(mut s), LiteralValue::StringValue(v)) => {
                 s.push_str(v.as_str());
-                LiteralValue::StringValue(s)
-            }
-            (_, _) => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(string::push()) The first argument must be a string and the other second argument must also be a string.",
-                )
-                .panic();
-
-                LiteralValue::Null
+                Ok(LiteralValue::StringValue(s))
             }
+            (_, _) => Err(
+                "(string::push()) The first argument must be a string and the other second argument must also be a string."
+                    .to_string(),
+            ),
         }
     }
 
-    pub fn replace(args: &[LiteralValue]) -> LiteralValue {
+    pub fn replace(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.len() != 3 {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(string::replace()) Should must have 3 arguments.",
-            )
-            .panic();
+            return Err("(string::replace()) Should must have 3 arguments.".to_string());
         }
 
         match (&args[0], &args[1], &args[2]) {
@@ -228,99 +255,391 @@ impl Strings {
                 LiteralValue::StringValue(s),
                 LiteralValue::StringValue(old),
                 LiteralValue::StringValue(new),
-            ) => LiteralValue::StringValue(s.replace(old, new)),
-            (_, _, _) => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(string::replace()) The correctly arguments are (source string, old string, new string).",
-                )
-                .panic();
-
-                LiteralValue::Null
-            }
+            ) => Ok(LiteralValue::StringValue(s.replace(old, new))),
+            (_, _, _) => Err(
+                "(string::replace()) The correctly arguments are (source string, old string, new string)."
+                    .to_string(),
+            ),
         }
     }
 
-    pub fn trim(args: &[LiteralValue]) -> LiteralValue {
+    pub fn trim(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.is_empty() {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(string::trim()) Should must have 1 arguments.",
-            )
-            .panic();
+            return Err("(string::trim()) Should must have 1 arguments.".to_string());
         }
 
         match &args[0] {
-            LiteralValue::StringValue(s) => LiteralValue::StringValue(s.replace(' ', "")),
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(string::trim()) The correctly arguments are (source string).",
-                )
-                .panic();
-
-                LiteralValue::Null
-            }
+            LiteralValue::StringValue(s) => Ok(LiteralValue::StringValue(s.replace(' ', ""))),
+            _ => Err("(string::trim()) The correctly arguments are (source string).".to_string()),
         }
     }
 
-    pub fn trim_left(args: &[LiteralValue]) -> LiteralValue {
+    pub fn trim_left(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.is_empty() {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(string::trim_l()) Should must have 1 arguments.",
-            )
-            .panic();
+            return Err("(string::trim_l()) Should must have 1 arguments.".to_string());
         }
 
         match &args[0] {
-            LiteralValue::StringValue(s) => LiteralValue::StringValue(s.trim_start().to_string()),
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(string::trim_l()) The correctly arguments are (source string).",
-                )
-                .panic();
-
-                LiteralValue::Null
+            LiteralValue::StringValue(s) => {
+                Ok(LiteralValue::StringValue(s.trim_start().to_string()))
             }
+            _ => Err("(string::trim_l()) The correctly arguments are (source string).".to_string()),
         }
     }
 
-    pub fn trim_right(args: &[LiteralValue]) -> LiteralValue {
+    pub fn trim_right(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.is_empty() {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(string::trim_r()) Should must have 1 arguments.",
-            )
-            .panic();
+            return Err("(string::trim_r()) Should must have 1 arguments.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(s) => Ok(LiteralValue::StringValue(s.trim_end().to_string())),
+            _ => Err("(string::trim_r()) The correctly arguments are (source string).".to_string()),
+        }
+    }
+
+    pub fn codepoints(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(string::codepoints()) Should must have 1 arguments.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(s) => Ok(LiteralValue::List(
+                s.chars()
+                    .map(|c| LiteralValue::Number(c as u32 as f64))
+                    .collect(),
+            )),
+            _ => Err(
+                "(string::codepoints()) The correctly arguments are (source string).".to_string(),
+            ),
+        }
+    }
+
+    pub fn contains(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(string::contains()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::StringValue(s), LiteralValue::StringValue(search)) => {
+                if s.contains(search.as_str()) {
+                    return Ok(LiteralValue::True);
+                }
+
+                Ok(LiteralValue::False)
+            }
+
+            (_, _) => Err(
+                "(string::contains()) The first argument must be a string and the other second argument must also be a string."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn starts_with(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(string::starts_with()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::StringValue(s), LiteralValue::StringValue(search)) => {
+                if s.starts_with(search.as_str()) {
+                    return Ok(LiteralValue::True);
+                }
+
+                Ok(LiteralValue::False)
+            }
+
+            (_, _) => Err(
+                "(string::starts_with()) The first argument must be a string and the other second argument must also be a string."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn ends_with(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(string::ends_with()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::StringValue(s), LiteralValue::StringValue(search)) => {
+                if s.ends_with(search.as_str()) {
+                    return Ok(LiteralValue::True);
+                }
+
+                Ok(LiteralValue::False)
+            }
+
+            (_, _) => Err(
+                "(string::ends_with()) The first argument must be a string and the other second argument must also be a string."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn substring(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 3 {
+            return Err("(string::substring()) Should must have 3 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1], &args[2]) {
+            (
+                LiteralValue::StringValue(s),
+                LiteralValue::Number(start),
+                LiteralValue::Number(end),
+            ) => {
+                let length: f64 = s.chars().count() as f64;
+
+                if *start < 0.0 || *end > length || start > end {
+                    return Err(format!(
+                        "(string::substring()) The range ({}, {}) is out of bounds for a string of length ({}).",
+                        start, end, length
+                    ));
+                }
+
+                Ok(LiteralValue::StringValue(
+                    s.chars()
+                        .skip(*start as usize)
+                        .take((*end - *start) as usize)
+                        .collect(),
+                ))
+            }
+            (_, _, _) => Err(
+                "(string::substring()) The correctly arguments are (source string, start number, end number)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn repeat(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(string::repeat()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::StringValue(s), LiteralValue::Number(n)) => {
+                if *n < 0.0 || n.fract() != 0.0 {
+                    return Err(
+                        "(string::repeat()) The count must be a positive integer or zero."
+                            .to_string(),
+                    );
+                }
+
+                if s.len() as f64 * n > NYX_MAX_STRING_LENGTH as f64 {
+                    return Err(format!(
+                        "(string::repeat()) The result would exceed the maximum string length ({} bytes).",
+                        NYX_MAX_STRING_LENGTH
+                    ));
+                }
+
+                allocate(s.len() * *n as usize)?;
+
+                Ok(LiteralValue::StringValue(s.repeat(*n as usize)))
+            }
+            (_, _) => Err(
+                "(string::repeat()) The correctly arguments are (source string, count number)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn visible_length(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(string::visible_length()) Should must have 1 arguments.".to_string());
         }
 
         match &args[0] {
-            LiteralValue::StringValue(s) => LiteralValue::StringValue(s.trim_end().to_string()),
-            _ => {
-                PanicHandler::new(
-                    None,
-                    None,
-                    None,
-                    "(string::trim_r()) The correctly arguments are (source string).",
-                )
-                .panic();
-
-                LiteralValue::Null
+            LiteralValue::StringValue(s) => Ok(LiteralValue::Number(
+                Self::strip_ansi(s).chars().count() as f64,
+            )),
+            _ => Err(
+                "(string::visible_length()) The correctly arguments are (source string)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    fn strip_ansi(s: &str) -> String {
+        let mut stripped: String = String::with_capacity(s.len());
+        let mut chars = s.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '\u{1b}' {
+                stripped.push(c);
+                continue;
+            }
+
+            if chars.next_if_eq(&'[').is_some() {
+                for c in chars.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&c) {
+                        break;
+                    }
+                }
+            } else {
+                chars.next();
+            }
+        }
+
+        stripped
+    }
+
+    pub fn swap_case(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(string::swap_case()) Should must have 1 arguments.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(s) => {
+                let mut swapped: String = String::with_capacity(s.len());
+
+                s.chars().for_each(|c| {
+                    if c.is_uppercase() {
+                        swapped.extend(c.to_lowercase());
+                    } else if c.is_lowercase() {
+                        swapped.extend(c.to_uppercase());
+                    } else {
+                        swapped.push(c);
+                    }
+                });
+
+                Ok(LiteralValue::StringValue(swapped))
+            }
+            _ => Err(
+                "(string::swap_case()) The correctly arguments are (source string).".to_string(),
+            ),
+        }
+    }
+
+    pub fn expand_tabs(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(string::expand_tabs()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::StringValue(s), LiteralValue::Number(width))
+                if *width >= 1.0 && width.fract() == 0.0 =>
+            {
+                let width: usize = *width as usize;
+                let mut expanded: String = String::with_capacity(s.len());
+                let mut column: usize = 0;
+
+                for c in s.chars() {
+                    match c {
+                        '\t' => {
+                            let spaces: usize = width - column % width;
+
+                            if expanded.len().saturating_add(spaces) > NYX_MAX_STRING_LENGTH {
+                                return Err(format!(
+                                    "(string::expand_tabs()) The result would exceed the maximum string length ({} bytes).",
+                                    NYX_MAX_STRING_LENGTH
+                                ));
+                            }
+
+                            allocate(spaces)?;
+                            expanded.push_str(&" ".repeat(spaces));
+                            column += spaces;
+                        }
+                        '\n' => {
+                            expanded.push(c);
+                            column = 0;
+                        }
+                        _ => {
+                            expanded.push(c);
+                            column += 1;
+                        }
+                    }
+                }
+
+                Ok(LiteralValue::StringValue(expanded))
+            }
+            _ => Err(
+                "(string::expand_tabs()) The correctly arguments are (source string, tab width integer greater than 0)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn fit(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 && args.len() != 3 {
+            return Err("(string::fit()) Should must have 2 or 3 arguments.".to_string());
+        }
+
+        let ellipsis: LiteralValue = args.get(2).cloned().unwrap_or(LiteralValue::False);
+
+        match (&args[0], &args[1], &ellipsis) {
+            (
+                LiteralValue::StringValue(s),
+                LiteralValue::Number(width),
+                LiteralValue::True | LiteralValue::False,
+            ) if *width >= 0.0 && width.fract() == 0.0 => {
+                let width: usize = *width as usize;
+                let length: usize = s.chars().count();
+
+                if length <= width {
+                    if s.len().saturating_add(width - length) > NYX_MAX_STRING_LENGTH {
+                        return Err(format!(
+                            "(string::fit()) The result would exceed the maximum string length ({} bytes).",
+                            NYX_MAX_STRING_LENGTH
+                        ));
+                    }
+
+                    allocate(width - length)?;
+
+                    return Ok(LiteralValue::StringValue(format!(
+                        "{}{}",
+                        s,
+                        " ".repeat(width - length)
+                    )));
+                }
+
+                if ellipsis == LiteralValue::False {
+                    return Ok(LiteralValue::StringValue(s.chars().take(width).collect()));
+                }
+
+                let keep: usize = width.saturating_sub(3);
+
+                Ok(LiteralValue::StringValue(
+                    s.chars()
+                        .take(keep)
+                        .chain("...".chars().take(width - keep))
+                        .collect(),
+                ))
+            }
+            _ => Err(
+                "(string::fit()) The correctly arguments are (source string, width non-negative integer, optional ellipsis bool)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn char_at(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(string::char_at()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::StringValue(s), LiteralValue::Number(index)) if index.fract() == 0.0 => {
+                let length: usize = s.chars().count();
+
+                if *index < 0.0 || *index as usize >= length {
+                    return Err(format!(
+                        "(string::char_at()) The index ({}) is out of bounds for a string of length ({}).",
+                        index, length
+                    ));
+                }
+
+                Ok(LiteralValue::StringValue(
+                    s.chars()
+                        .nth(*index as usize)
+                        .unwrap_or_default()
+                        .to_string(),
+                ))
             }
+            _ => Err(
+                "(string::char_at()) The correctly arguments are (source string, integer index)."
+                    .to_string(),
+            ),
         }
     }
 }
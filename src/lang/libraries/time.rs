@@ -0,0 +1,121 @@
+use std::{
+    collections::HashMap,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::super::expr::{LiteralValue, NativeFunctionImpl};
+
+pub struct Time;
+
+impl Time {
+    pub fn gen_tree_methods() -> HashMap<&'static str, NativeFunctionImpl> {
+        let mut methods: HashMap<&'static str, NativeFunctionImpl> = HashMap::new();
+
+        methods.insert(
+            "now_millis",
+            NativeFunctionImpl {
+                name: "now_millis",
+                fc: Rc::new(Self::now_millis),
+            },
+        );
+
+        methods.insert(
+            "now_secs",
+            NativeFunctionImpl {
+                name: "now_secs",
+                fc: Rc::new(Self::now_secs),
+            },
+        );
+
+        methods.insert(
+            "format",
+            NativeFunctionImpl {
+                name: "format",
+                fc: Rc::new(Self::format),
+            },
+        );
+
+        methods
+    }
+
+    pub fn now_millis(_args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Ok(LiteralValue::Number(Self::since_epoch()?.as_millis() as f64))
+    }
+
+    pub fn now_secs(_args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Ok(LiteralValue::Number(Self::since_epoch()?.as_secs() as f64))
+    }
+
+    pub fn format(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(time::format()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::Number(secs), LiteralValue::StringValue(fmt)) if secs.is_finite() => {
+                let secs: i64 = secs.floor() as i64;
+                let (year, month, day) = Self::civil_from_days(secs.div_euclid(86400));
+                let seconds_of_day: i64 = secs.rem_euclid(86400);
+
+                let mut out: String = String::with_capacity(fmt.len());
+                let mut chars = fmt.chars();
+
+                while let Some(c) = chars.next() {
+                    if c != '%' {
+                        out.push(c);
+                        continue;
+                    }
+
+                    match chars.next() {
+                        Some('Y') => out.push_str(&format!("{:04}", year)),
+                        Some('m') => out.push_str(&format!("{:02}", month)),
+                        Some('d') => out.push_str(&format!("{:02}", day)),
+                        Some('H') => out.push_str(&format!("{:02}", seconds_of_day / 3600)),
+                        Some('M') => out.push_str(&format!("{:02}", seconds_of_day % 3600 / 60)),
+                        Some('S') => out.push_str(&format!("{:02}", seconds_of_day % 60)),
+                        Some('%') => out.push('%'),
+                        Some(token) => {
+                            return Err(format!(
+                                "(time::format()) Unknown format token (%{}).",
+                                token
+                            ))
+                        }
+                        None => {
+                            return Err(
+                                "(time::format()) The format cannot end with a lone '%'."
+                                    .to_string(),
+                            )
+                        }
+                    }
+                }
+
+                Ok(LiteralValue::StringValue(out))
+            }
+            _ => Err(
+                "(time::format()) The correctly arguments are (epoch seconds number, format string)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    fn since_epoch() -> Result<std::time::Duration, String> {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| "Could not get system time.".to_string())
+    }
+
+    fn civil_from_days(days: i64) -> (i64, i64, i64) {
+        let z: i64 = days + 719468;
+        let era: i64 = z.div_euclid(146097);
+        let doe: i64 = z.rem_euclid(146097);
+        let yoe: i64 = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy: i64 = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp: i64 = (5 * doy + 2) / 153;
+        let day: i64 = doy - (153 * mp + 2) / 5 + 1;
+        let month: i64 = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year: i64 = yoe + era * 400 + i64::from(month <= 2);
+
+        (year, month, day)
+    }
+}
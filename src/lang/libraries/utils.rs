@@ -1,8 +1,12 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use super::super::{
-    expr::{LiteralValue, NativeFunctionImpl},
-    panic::PanicHandler,
+use super::{
+    super::{
+        constants::NYX_MAX_SAFE_INTEGER,
+        expr::{allocate, LiteralValue, NativeFunctionImpl},
+        panic::PanicHandler,
+    },
+    list::List,
 };
 
 pub struct Utils;
@@ -27,46 +31,425 @@ impl Utils {
             },
         );
 
+        methods.insert(
+            "copy_fields",
+            NativeFunctionImpl {
+                name: "copy_fields",
+                fc: Rc::new(Self::copy_fields),
+            },
+        );
+
+        methods.insert(
+            "ensure",
+            NativeFunctionImpl {
+                name: "ensure",
+                fc: Rc::new(Self::ensure),
+            },
+        );
+
+        methods.insert(
+            "sort_keys",
+            NativeFunctionImpl {
+                name: "sort_keys",
+                fc: Rc::new(Self::sort_keys),
+            },
+        );
+
+        methods.insert(
+            "format_number",
+            NativeFunctionImpl {
+                name: "format_number",
+                fc: Rc::new(Self::format_number),
+            },
+        );
+
+        methods.insert(
+            "assert",
+            NativeFunctionImpl {
+                name: "assert",
+                fc: Rc::new(Self::assert),
+            },
+        );
+
+        methods.insert(
+            "assert_eq",
+            NativeFunctionImpl {
+                name: "assert_eq",
+                fc: Rc::new(Self::assert_eq),
+            },
+        );
+
+        methods.insert(
+            "freeze",
+            NativeFunctionImpl {
+                name: "freeze",
+                fc: Rc::new(Self::freeze),
+            },
+        );
+
+        methods.insert(
+            "is_null",
+            NativeFunctionImpl {
+                name: "is_null",
+                fc: Rc::new(Self::is_null),
+            },
+        );
+
+        methods.insert(
+            "is_number",
+            NativeFunctionImpl {
+                name: "is_number",
+                fc: Rc::new(Self::is_number),
+            },
+        );
+
+        methods.insert(
+            "is_string",
+            NativeFunctionImpl {
+                name: "is_string",
+                fc: Rc::new(Self::is_string),
+            },
+        );
+
+        methods.insert(
+            "is_bool",
+            NativeFunctionImpl {
+                name: "is_bool",
+                fc: Rc::new(Self::is_bool),
+            },
+        );
+
+        methods.insert(
+            "is_list",
+            NativeFunctionImpl {
+                name: "is_list",
+                fc: Rc::new(Self::is_list),
+            },
+        );
+
+        methods.insert(
+            "is_callable",
+            NativeFunctionImpl {
+                name: "is_callable",
+                fc: Rc::new(Self::is_callable),
+            },
+        );
+
+        methods.insert(
+            "to_bool",
+            NativeFunctionImpl {
+                name: "to_bool",
+                fc: Rc::new(Self::to_bool),
+            },
+        );
+
+        methods.insert(
+            "to_int",
+            NativeFunctionImpl {
+                name: "to_int",
+                fc: Rc::new(Self::to_int),
+            },
+        );
+
+        methods.insert(
+            "fields",
+            NativeFunctionImpl {
+                name: "fields",
+                fc: Rc::new(Self::fields),
+            },
+        );
+
         methods
     }
 
-    pub fn get_type(args: &[LiteralValue]) -> LiteralValue {
+    pub fn get_type(args: &[LiteralValue]) -> Result<LiteralValue, String> {
         if args.is_empty() {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(utils::type()) Should must have 1 argument.",
-            )
-            .panic();
+            return Err("(utils::type()) Should must have 1 argument.".to_string());
+        }
+
+        Ok(LiteralValue::StringValue(args[0].to_type().to_string()))
+    }
 
-            return LiteralValue::Null;
+    pub fn parse(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(utils::parse()) Should must have 1 argument.".to_string());
         }
 
-        LiteralValue::StringValue(args[0].to_type().to_string())
+        match &args[0] {
+            LiteralValue::StringValue(s) => match s.trim().parse::<f64>() {
+                Ok(n) if n.is_finite() => Ok(LiteralValue::Number(n)),
+                _ => Ok(LiteralValue::Null),
+            },
+            LiteralValue::Number(n) => Ok(LiteralValue::StringValue(n.to_string())),
+            _ => {
+                Err("(utils::parse()) The correctly arguments are (string or number).".to_string())
+            }
+        }
     }
 
-    pub fn parse(args: &[LiteralValue]) -> LiteralValue {
-        if args.is_empty() {
-            PanicHandler::new(
-                None,
-                None,
-                None,
-                "(utils::parse()) Should must have 1 argument.",
-            )
-            .panic();
+    pub fn to_bool(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(utils::to_bool()) Should must have 1 argument.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(s) => match s.trim() {
+                "true" => Ok(LiteralValue::True),
+                "false" => Ok(LiteralValue::False),
+                _ => Ok(LiteralValue::Null),
+            },
+            _ => Ok(LiteralValue::Null),
+        }
+    }
+
+    pub fn to_int(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(utils::to_int()) Should must have 1 argument.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::StringValue(s) => match s.trim().parse::<i64>() {
+                Ok(n) if n.unsigned_abs() <= NYX_MAX_SAFE_INTEGER as u64 => {
+                    Ok(LiteralValue::Number(n as f64))
+                }
+                _ => Ok(LiteralValue::Null),
+            },
+            _ => Ok(LiteralValue::Null),
+        }
+    }
+
+    pub fn copy_fields(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(utils::copy_fields()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (
+                LiteralValue::ClassInstance { fields: target, .. },
+                LiteralValue::ClassInstance { fields: source, .. },
+            ) => {
+                let source: Vec<(String, LiteralValue)> = source.borrow().clone();
+                let mut target_fields = target.borrow_mut();
+
+                source.into_iter().for_each(|(name, value)| {
+                    match target_fields.iter_mut().find(|(field, _)| *field == name) {
+                        Some(field) => field.1 = value,
+                        None => target_fields.push((name, value)),
+                    }
+                });
+
+                Ok(args[0].to_owned())
+            }
+            (_, _) => {
+                Err("(utils::copy_fields()) Both arguments must be clazz instances.".to_string())
+            }
+        }
+    }
+
+    pub fn ensure(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(utils::ensure()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::Callable(body), LiteralValue::Callable(cleanup)) => {
+                let rs: Result<LiteralValue, String> = body.call(&[]);
+
+                cleanup.call(&[])?;
+
+                rs
+            }
+            (_, _) => Err(
+                "(utils::ensure()) The correctly arguments are (body callable, cleanup callable)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn sort_keys(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(utils::sort_keys()) Should must have 1 argument.".to_string());
         }
 
         match &args[0] {
-            LiteralValue::StringValue(s) => {
-                if let Ok(n) = s.parse::<f64>() {
-                    return LiteralValue::Number(n);
+            LiteralValue::List(pairs) => {
+                let mut keys: Vec<LiteralValue> = Vec::with_capacity(pairs.len());
+
+                for pair in pairs {
+                    match pair {
+                        LiteralValue::List(pair) if pair.len() == 2 => {
+                            keys.push(pair[0].to_owned())
+                        }
+                        _ => {
+                            return Err(
+                                "(utils::sort_keys()) Each element must be a [key, value] list."
+                                    .to_string(),
+                            )
+                        }
+                    }
                 }
 
-                LiteralValue::Null
+                List::sort(&[LiteralValue::List(keys)])
             }
-            LiteralValue::Number(n) => LiteralValue::StringValue(n.to_string()),
-            _ => LiteralValue::Null,
+            _ => Err(
+                "(utils::sort_keys()) The correctly arguments are (list of [key, value] lists)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn format_number(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(utils::format_number()) Should must have 2 arguments.".to_string());
+        }
+
+        match (&args[0], &args[1]) {
+            (LiteralValue::Number(n), LiteralValue::Number(decimals)) => {
+                if *decimals < 0.0 || decimals.fract() != 0.0 || !decimals.is_finite() {
+                    return Err(
+                        "(utils::format_number()) The decimals must be a non-negative integer."
+                            .to_string(),
+                    );
+                }
+
+                allocate(*decimals as usize)?;
+
+                Ok(LiteralValue::StringValue(format!("{:.*}", *decimals as usize, n)))
+            }
+            _ => Err(
+                "(utils::format_number()) The correctly arguments are (value number, decimals number)."
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn assert(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(utils::assert()) Should must have 1 argument.".to_string());
+        }
+
+        if args[0].truthy()? == LiteralValue::True {
+            return Ok(LiteralValue::Null);
+        }
+
+        Err(PanicHandler::new(
+            None,
+            None,
+            None,
+            &format!(
+                "(utils::assert()) Assertion failed, the value ({}) is not truthy.",
+                args[0].convert()
+            ),
+        )
+        .format())
+    }
+
+    pub fn assert_eq(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 2 {
+            return Err("(utils::assert_eq()) Should must have 2 arguments.".to_string());
+        }
+
+        if args[0] == args[1] {
+            return Ok(LiteralValue::Null);
+        }
+
+        Err(PanicHandler::new(
+            None,
+            None,
+            None,
+            &format!(
+                "(utils::assert_eq()) Assertion failed, the left value ({}) is not equal to the right value ({}).",
+                args[0].convert(),
+                args[1].convert()
+            ),
+        )
+        .format())
+    }
+
+    pub fn freeze(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(utils::freeze()) Should must have 1 argument.".to_string());
+        }
+
+        Ok(Self::detach(&args[0]))
+    }
+
+    fn detach(value: &LiteralValue) -> LiteralValue {
+        match value {
+            LiteralValue::List(list) => LiteralValue::List(list.iter().map(Self::detach).collect()),
+            LiteralValue::ClassInstance { class, fields } => LiteralValue::ClassInstance {
+                class: class.to_owned(),
+                fields: Rc::new(RefCell::new(
+                    fields
+                        .borrow()
+                        .iter()
+                        .map(|(name, value)| (name.to_owned(), Self::detach(value)))
+                        .collect(),
+                )),
+            },
+            _ => value.to_owned(),
+        }
+    }
+
+    pub fn is_null(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::is_type("is_null", args, |value| value.to_type() == "null")
+    }
+
+    pub fn is_number(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::is_type("is_number", args, |value| value.to_type() == "number")
+    }
+
+    pub fn is_string(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::is_type("is_string", args, |value| value.to_type() == "string")
+    }
+
+    pub fn is_bool(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::is_type("is_bool", args, |value| value.to_type() == "boolean")
+    }
+
+    pub fn is_list(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::is_type("is_list", args, |value| value.to_type() == "list")
+    }
+
+    pub fn is_callable(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        Self::is_type("is_callable", args, |value| {
+            matches!(
+                value,
+                LiteralValue::Callable(_) | LiteralValue::Clazz { .. }
+            )
+        })
+    }
+
+    fn is_type(
+        name: &str,
+        args: &[LiteralValue],
+        check: fn(&LiteralValue) -> bool,
+    ) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err(format!("(utils::{}()) Should must have 1 argument.", name));
+        }
+
+        if check(&args[0]) {
+            return Ok(LiteralValue::True);
+        }
+
+        Ok(LiteralValue::False)
+    }
+
+    pub fn fields(args: &[LiteralValue]) -> Result<LiteralValue, String> {
+        if args.len() != 1 {
+            return Err("(utils::fields()) Should must have 1 argument.".to_string());
+        }
+
+        match &args[0] {
+            LiteralValue::ClassInstance { fields, .. } => Ok(LiteralValue::List(
+                fields
+                    .borrow()
+                    .iter()
+                    .map(|(name, _)| LiteralValue::StringValue(name.to_owned()))
+                    .collect(),
+            )),
+            _ => Err("(utils::fields()) The correctly arguments are (clazz instance).".to_string()),
         }
     }
 }
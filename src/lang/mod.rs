@@ -1,6 +1,8 @@
 pub mod constants;
+pub mod docs;
 pub mod environment;
 pub mod expr;
+pub mod folder;
 pub mod interpreter;
 pub mod libraries;
 pub mod panic;
@@ -12,7 +14,13 @@ pub mod types;
 pub mod utils;
 
 use self::{
-    constants::{NYX_FILE_SUFFIX, NYX_OK},
+    constants::{
+        NYX_ERROR, NYX_FILE_SUFFIX, NYX_MAX_CALL_DEPTH, NYX_MAX_CALL_DEPTH_LIMIT, NYX_OK,
+        NYX_STACK_SIZE,
+    },
+    docs::{docs, modules, NyxDoc},
+    expr::{set_max_depth, LiteralValue},
+    folder::Folder,
     interpreter::*,
     panic::PanicHandler,
     parser::NyxParser,
@@ -20,14 +28,22 @@ use self::{
     stmt::Stmt,
     tokenizer::{NyxTokenizer, Token},
     types::NyxResult,
-    utils::formatter,
+    utils::{formatter, is_balanced},
 };
 
-use std::{collections::HashMap, fs::read_to_string, path::Path, process::exit};
+use std::{
+    collections::HashMap,
+    fs::read_to_string,
+    io::{stdin, stdout, IsTerminal, Write},
+    panic::resume_unwind,
+    path::Path,
+    process::exit,
+    thread::Builder,
+};
 
 use clap::{
     builder::{styling::AnsiColor, Styles},
-    crate_version, Arg, ArgMatches,
+    crate_version, Arg, ArgAction, ArgMatches,
     ColorChoice::Always,
     Command,
 };
@@ -45,6 +61,25 @@ impl Nyx {
             .arg_required_else_help(true)
             .color(Always)
             .styles(self.styles())
+            .arg(
+                Arg::new("no-color")
+                    .long("no-color")
+                    .action(ArgAction::SetTrue)
+                    .global(true)
+                    .help("Disable colored output, also available through NO_COLOR."),
+            )
+            .arg(
+                Arg::new("max-depth")
+                    .long("max-depth")
+                    .value_parser(
+                        clap::value_parser!(u64).range(1..=NYX_MAX_CALL_DEPTH_LIMIT as u64),
+                    )
+                    .global(true)
+                    .help(format!(
+                        "Maximum depth of nested function calls before an error is raised, up to {}.",
+                        NYX_MAX_CALL_DEPTH_LIMIT
+                    )),
+            )
             .subcommand(
                 Command::new("run")
                     .arg(
@@ -55,28 +90,107 @@ impl Nyx {
                     )
                     .about("Run a Nyx file."),
             )
-            .subcommand(Command::new("doc").about("Search documentation for commands or errors."))
+            .subcommand(
+                Command::new("tokens")
+                    .arg(
+                        Arg::new("path")
+                            .help_heading("The direction of the file to tokenize.")
+                            .required(true)
+                            .require_equals(false),
+                    )
+                    .about("Print the token stream of a Nyx file."),
+            )
+            .subcommand(
+                Command::new("ast")
+                    .arg(
+                        Arg::new("path")
+                            .help_heading("The direction of the file to parse.")
+                            .required(true)
+                            .require_equals(false),
+                    )
+                    .arg(
+                        Arg::new("fold")
+                            .long("fold")
+                            .action(ArgAction::SetTrue)
+                            .help("Print the tree after folding constant expressions and calls to pure functions."),
+                    )
+                    .about("Print the statement tree of a Nyx file."),
+            )
+            .subcommand(
+                Command::new("check")
+                    .arg(
+                        Arg::new("path")
+                            .help_heading("The direction of the file to check.")
+                            .required(true)
+                            .require_equals(false),
+                    )
+                    .about("Report every syntax error of a Nyx file without running it."),
+            )
+            .subcommand(Command::new("repl").about("Start an interactive Nyx session."))
+            .subcommand(
+                Command::new("doc")
+                    .alias("docs")
+                    .arg(
+                        Arg::new("name")
+                            .help_heading("The function to describe, as module::function.")
+                            .required(false)
+                            .require_equals(false),
+                    )
+                    .about("Print the documentation of the standard library."),
+            )
             .subcommand(Command::new("creator").about("View the talented developer."))
             .get_matches();
 
-        self.analyze(&matches);
+        let interpreter = Builder::new()
+            .stack_size(NYX_STACK_SIZE)
+            .spawn(move || Nyx.analyze(&matches))
+            .unwrap_or_else(|e| {
+                PanicHandler::new(None, None, None, &format!("Could not start Nyx: {}.", e)).exit()
+            });
+
+        if let Err(any) = interpreter.join() {
+            resume_unwind(any);
+        }
     }
 
     fn analyze(&self, matches: &ArgMatches) {
+        if matches.get_flag("no-color") {
+            control::set_override(false);
+        }
+
+        let max_depth: usize = matches
+            .get_one::<u64>("max-depth")
+            .map_or(NYX_MAX_CALL_DEPTH, |depth| *depth as usize);
+
         match matches.subcommand() {
             Some(("run", matches)) => {
                 if let Some(file_path) = matches.get_one::<String>("path") {
-                    self.analyze_file(file_path);
+                    self.analyze_file(file_path, max_depth);
                 };
             }
 
-            Some(("doc" | "docs", _)) => {
-                println!(
-                    "{}",
-                    formatter(true, true, &["Comming Soon...".bold().bright_yellow()])
-                );
+            Some(("tokens", matches)) => {
+                if let Some(file_path) = matches.get_one::<String>("path") {
+                    self.tokens(file_path);
+                };
+            }
+
+            Some(("ast", matches)) => {
+                if let Some(file_path) = matches.get_one::<String>("path") {
+                    self.ast(file_path, matches.get_flag("fold"));
+                };
             }
 
+            Some(("check", matches)) => {
+                if let Some(file_path) = matches.get_one::<String>("path") {
+                    self.check(file_path);
+                };
+            }
+
+            Some(("repl", _)) => self.repl(max_depth),
+
+            Some(("doc", matches)) => self.doc(matches.get_one::<String>("name")),
+
             Some(("creator", _)) => open("https://github.com/DevCheckOG").unwrap_or(()),
 
             _ => PanicHandler::new(
@@ -89,7 +203,152 @@ impl Nyx {
         };
     }
 
-    fn analyze_file(&self, path: &str) {
+    fn analyze_file(&self, path: &str, max_depth: usize) {
+        match self.run_file(&self.read_file(path), max_depth) {
+            Ok(()) => exit(NYX_OK),
+            Err(any) => PanicHandler::new(None, None, None, any.as_str()).exit(),
+        }
+    }
+
+    fn tokens(&self, path: &str) {
+        let content: String = self.read_file(path);
+        let mut tokenizer: NyxTokenizer = NyxTokenizer::new(&content);
+
+        match tokenizer.analyze() {
+            Ok(tokens) => tokens.iter().for_each(|token| {
+                println!(
+                    "{:?} '{}' ({}:{})",
+                    token.token_type, token.lexeme, token.line, token.column
+                );
+            }),
+            Err(any) => PanicHandler::new(None, None, None, any.as_str()).exit(),
+        }
+    }
+
+    fn ast(&self, path: &str, fold: bool) {
+        let content: String = self.read_file(path);
+        let mut tokenizer: NyxTokenizer = NyxTokenizer::new(&content);
+
+        let stmts: Result<Vec<Stmt>, String> = tokenizer
+            .analyze()
+            .and_then(|tokens| {
+                NyxParser::new(tokens)
+                    .parse()
+                    .map(|stmts| stmts.to_vec())
+                    .map_err(|errors| errors.join("\n"))
+            })
+            .and_then(|stmts| {
+                if !fold {
+                    return Ok(stmts);
+                }
+
+                let locals: HashMap<usize, usize> = Resolver::new().resolve(&stmts)?;
+                Ok(Folder::new(&stmts, &locals).fold(&stmts))
+            });
+
+        match stmts {
+            Ok(stmts) => stmts
+                .iter()
+                .for_each(|stmt| println!("{}", stmt.convert(0))),
+            Err(any) => PanicHandler::new(None, None, None, any.as_str()).exit(),
+        }
+    }
+
+    fn check(&self, path: &str) {
+        let content: String = self.read_file(path);
+        let mut tokenizer: NyxTokenizer = NyxTokenizer::new(&content);
+
+        let errors: Vec<String> = match tokenizer.analyze() {
+            Ok(tokens) => match NyxParser::new(tokens).parse() {
+                Ok(stmts) => Resolver::new().resolve(stmts).err().into_iter().collect(),
+                Err(errors) => errors,
+            },
+            Err(any) => vec![any],
+        };
+
+        if errors.is_empty() {
+            println!("{}", "No errors found.".bold().bright_green());
+            exit(NYX_OK);
+        }
+
+        errors.iter().for_each(|error| {
+            eprintln!("{} {}", "error:".bold().bright_red(), error.trim());
+        });
+
+        eprintln!(
+            "{}",
+            format!("{} error(s) found.", errors.len())
+                .bold()
+                .bright_red()
+        );
+
+        exit(NYX_ERROR);
+    }
+
+    fn doc(&self, name: Option<&String>) {
+        let docs: HashMap<&str, NyxDoc> = docs();
+
+        let Some(name) = name else {
+            println!("{}", "Modules:".bold().bright_white());
+
+            modules(&docs).iter().for_each(|(module, functions)| {
+                println!(
+                    "  {} {}",
+                    format!("{}:", module).bold(),
+                    functions.join(", ")
+                );
+            });
+
+            return;
+        };
+
+        if let Some(doc) = docs.get(name.as_str()) {
+            println!(
+                "{}",
+                formatter(
+                    false,
+                    false,
+                    &[
+                        format!("{}({})", name, doc.arguments.join(", "))
+                            .bold()
+                            .bright_white(),
+                        format!("\n  {}", doc.description).normal(),
+                        format!("\n  Arity: {}", doc.arity).normal(),
+                        format!(
+                            "\n  Arguments: {}",
+                            if doc.arguments.is_empty() {
+                                "none".to_string()
+                            } else {
+                                doc.arguments.join(", ")
+                            }
+                        )
+                        .normal(),
+                    ]
+                )
+            );
+
+            return;
+        }
+
+        match modules(&docs).iter().find(|(module, _)| module == name) {
+            Some((module, functions)) => functions.iter().for_each(|function| {
+                let key: String = format!("{}::{}", module, function);
+                println!("{} {}", key.bold(), docs[key.as_str()].description);
+            }),
+            None => PanicHandler::new(
+                None,
+                None,
+                None,
+                &format!(
+                    "Unknown documentation entry ({}). View 'nyx doc' for the available modules.",
+                    name
+                ),
+            )
+            .exit(),
+        }
+    }
+
+    fn read_file(&self, path: &str) -> String {
         if !path.ends_with(NYX_FILE_SUFFIX) {
             PanicHandler::new(
                 None,
@@ -111,12 +370,7 @@ impl Nyx {
         }
 
         if let Ok(cont) = read_to_string(path) {
-            match self.run_file(&cont) {
-                Ok(()) => exit(NYX_OK),
-                Err(any) => {
-                    PanicHandler::new(None, None, None, any.as_str()).panic();
-                }
-            }
+            return cont;
         }
 
         PanicHandler::new(
@@ -125,24 +379,94 @@ impl Nyx {
             None,
             "Uknown read error. View 'zynix || zynix.exe --help'",
         )
-        .panic()
+        .panic();
+
+        unreachable!()
+    }
+
+    fn run_file(&self, content: &str, max_depth: usize) -> NyxResult<'_> {
+        let mut interpreter: NyxInterpreter = NyxInterpreter::new();
+        interpreter.max_depth = max_depth;
+
+        interpreter.run_source(content)
     }
 
-    fn run_file(&self, content: &str) -> NyxResult {
+    fn repl(&self, max_depth: usize) {
         let mut interpreter: NyxInterpreter = NyxInterpreter::new();
+        interpreter.max_depth = max_depth;
+        set_max_depth(max_depth);
+
+        let mut id: usize = 0;
+        let mut buffer: String = String::new();
+        let interactive: bool = stdin().is_terminal();
+
+        loop {
+            if interactive {
+                print!("{}", if buffer.is_empty() { "> " } else { "... " });
+                stdout().flush().ok();
+            }
+
+            let mut line: String = String::new();
+
+            match stdin().read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+
+            if buffer.is_empty() && line.trim() == ":quit" {
+                break;
+            }
 
+            buffer.push_str(&line);
+
+            if !is_balanced(&buffer) {
+                continue;
+            }
+
+            let entry: String = buffer.trim().to_string();
+            buffer.clear();
+
+            if entry.is_empty() {
+                continue;
+            }
+
+            if let Err(any) = self.run_entry(&mut interpreter, &entry, &mut id) {
+                eprintln!("{}", any.bold().bright_red());
+            }
+        }
+    }
+
+    fn run_entry(
+        &self,
+        interpreter: &mut NyxInterpreter,
+        content: &str,
+        id: &mut usize,
+    ) -> NyxResult<'_> {
         let mut tokenizer: NyxTokenizer = NyxTokenizer::new(content);
         let tokens: &Vec<Token> = tokenizer.analyze()?;
 
-        let mut parser: NyxParser = NyxParser::new(tokens);
-        let stmts: &Vec<Stmt> = parser.parse()?;
+        let mut parser: NyxParser = NyxParser::with_id(tokens, *id);
+        let stmts: Vec<Stmt> = parser.parse().map_err(|errors| errors.join("\n"))?.to_vec();
 
-        let resolver: Resolver = Resolver::new();
-        let locals: HashMap<usize, usize> = resolver.resolve(stmts.iter().as_slice())?;
+        *id = parser.next_id();
+
+        let resolver: Resolver = Resolver::for_repl(interpreter.environment.names());
+        let locals: HashMap<usize, usize> = resolver.resolve(stmts.as_slice())?;
 
         interpreter.resolve(locals);
 
-        interpreter.interpret(stmts.iter().collect())?;
+        for stmt in stmts.iter() {
+            match stmt {
+                Stmt::Expression { expr } => {
+                    let value: LiteralValue = expr.evaluate(&interpreter.environment)?;
+
+                    if value != LiteralValue::Null {
+                        println!("{}", value.convert());
+                    }
+                }
+                _ => interpreter.interpret(vec![stmt])?,
+            }
+        }
 
         Ok(())
     }
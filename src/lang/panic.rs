@@ -1,3 +1,7 @@
+use std::{any::Any, process::exit};
+
+use super::constants::NYX_ERROR;
+
 pub struct PanicHandler<'a> {
     pub line: Option<usize>,
     pub column: Option<usize>,
@@ -21,10 +25,19 @@ impl<'a> PanicHandler<'a> {
     }
 
     pub fn panic(&self) {
+        panic!("{}", self.format());
+    }
+
+    pub fn exit(&self) -> ! {
+        eprintln!("{}", self.format());
+        exit(NYX_ERROR);
+    }
+
+    pub fn format(&self) -> String {
         if self.line.is_none() && self.column.is_none() && self.source.is_none() {
-            panic!("\n{}\n", self.message);
+            return format!("\n{}\n", self.message);
         } else if self.source.unwrap().is_empty() {
-            panic!(
+            return format!(
                 "\n{} ({}:{})\n",
                 self.message,
                 self.line.unwrap(),
@@ -32,12 +45,58 @@ impl<'a> PanicHandler<'a> {
             );
         }
 
-        panic!(
+        format!(
             "\n{} ({}:{})\n\n-----> {} <-----\n",
             self.message,
             self.line.unwrap(),
             self.column.unwrap(),
             self.source.unwrap()
-        );
+        )
+    }
+
+    pub fn payload(payload: Box<dyn Any + Send>) -> String {
+        payload
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| payload.downcast_ref::<&str>().map(|msg| msg.to_string()))
+            .unwrap_or_else(|| "The interpreter panicked.".to_string())
+    }
+
+    pub fn with_context(message: &str, source: &str) -> String {
+        let Some(offset) = message.lines().rev().find_map(Self::position) else {
+            return message.to_string();
+        };
+
+        let offset: usize = offset.saturating_sub(1);
+
+        if offset >= source.len() || !source.is_char_boundary(offset) {
+            return message.to_string();
+        }
+
+        let start: usize = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end: usize = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+
+        let line: String = (source[..start].matches('\n').count() + 1).to_string();
+        let gutter: String = " ".repeat(line.len());
+        let caret: String = " ".repeat(source[start..offset].chars().count());
+
+        format!(
+            "{}\n\n{} | {}\n{} | {}^",
+            message.trim(),
+            line,
+            source[start..end].trim_end(),
+            gutter,
+            caret
+        )
+    }
+
+    fn position(line: &str) -> Option<usize> {
+        let (_, position) = line.trim_end().strip_suffix(')')?.rsplit_once('(')?;
+        let (line, column) = position.split_once(':')?;
+
+        line.parse::<usize>().ok()?;
+        column.parse::<usize>().ok()
     }
 }
@@ -1,10 +1,9 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{collections::HashMap, mem, rc::Rc};
 
 use colored::*;
 
 use super::{
     expr::{Expr, Expr::*, LiteralValue},
-    panic::PanicHandler,
     stmt::Stmt,
     tokenizer::{Token, TokenType, TokenType::*},
     types::{NyxInternalParserResult, NyxParserResult},
@@ -17,6 +16,7 @@ pub struct NyxParser<'a> {
     current: usize,
     loop_nesting: u16,
     return_nesting: u16,
+    labels: Vec<String>,
     id: usize,
 }
 
@@ -29,11 +29,23 @@ impl<'a> NyxParser<'a> {
             current: 0,
             loop_nesting: 0,
             return_nesting: 0,
+            labels: Vec::new(),
             id: 0,
         }
     }
 
-    pub fn parse(&mut self) -> NyxParserResult {
+    pub fn with_id(tokens: &'a Vec<Token>, id: usize) -> Self {
+        Self {
+            id,
+            ..Self::new(tokens)
+        }
+    }
+
+    pub fn next_id(&self) -> usize {
+        self.id
+    }
+
+    pub fn parse(&mut self) -> NyxParserResult<'_> {
         while !self.is_at_end() {
             match self.declaration() {
                 Ok(s) => self.stmts.push(s),
@@ -45,7 +57,7 @@ impl<'a> NyxParser<'a> {
         }
 
         if !self.errors.is_empty() {
-            return Err(self.errors.join("\n"));
+            return Err(self.errors.clone());
         }
 
         Ok(&self.stmts)
@@ -57,7 +69,17 @@ impl<'a> NyxParser<'a> {
         } else if self.match_token(Const) {
             return self.const_declaration();
         } else if self.match_token(Fc) {
-            return self.function();
+            return self.function(false);
+        } else if self.match_token(Pure) {
+            self.consume(
+                Fc,
+                format!(
+                    "Expected 'fc' keyword after 'pure'. ({}:{})",
+                    self.tokens[self.current].line, self.tokens[self.current].column
+                ),
+            )?;
+
+            return self.function(true);
         } else if self.match_token(Clazz) {
             return self.clazz_declaration();
         } else if self.match_tokens(&[Lib, Std]) {
@@ -107,7 +129,7 @@ impl<'a> NyxParser<'a> {
             ),
         ) {
             Ok(_) => {
-                if self.std_md().get(module.lexeme.as_str()).is_some() {
+                if self.std_md().contains_key(module.lexeme.as_str()) {
                     return Ok(Stmt::Std {
                         module: module.lexeme,
                         fc: None,
@@ -183,7 +205,7 @@ impl<'a> NyxParser<'a> {
                             ),
                         )?;
 
-                        if self.std_md().get(module.lexeme.as_str()).is_some() {
+                        if self.std_md().contains_key(module.lexeme.as_str()) {
                             return Ok(Stmt::Std {
                                 module: module.lexeme,
                                 fc: Some(functions),
@@ -282,7 +304,7 @@ impl<'a> NyxParser<'a> {
         let mut methods: Vec<Stmt> = vec![];
 
         while !self.check(RightBrace) && !self.is_at_end() {
-            methods.push(self.function()?);
+            methods.push(self.function(false)?);
         }
 
         self.consume(
@@ -300,7 +322,7 @@ impl<'a> NyxParser<'a> {
         })
     }
 
-    fn function(&mut self) -> NyxInternalParserResult {
+    fn function(&mut self, pure: bool) -> NyxInternalParserResult {
         let name: Token = self.consume(
             Identifier,
             format!(
@@ -359,23 +381,25 @@ impl<'a> NyxParser<'a> {
             ),
         )?;
 
-        self.return_nesting += 1;
+        let enclosing: (u16, Vec<String>) = self.enter_function();
+        let body: Result<Stmt, String> = self.block_statement();
 
-        let body: Vec<Stmt> = match self.block_statement()? {
+        self.exit_function(enclosing);
+
+        let body: Vec<Stmt> = match body? {
             Stmt::Block { statements } => statements,
             _ => panic!("Block statement parsed something that was not a block"),
         };
 
-        self.return_nesting -= 1;
-
         Ok(Stmt::Function {
             name,
             params: parameters,
             body,
+            pure,
         })
     }
 
-    fn foreach_statement(&mut self) -> NyxInternalParserResult {
+    fn foreach_statement(&mut self, label: Option<Token>) -> NyxInternalParserResult {
         let var: Token = self.peek();
 
         self.advance();
@@ -388,31 +412,32 @@ impl<'a> NyxParser<'a> {
             ),
         )?;
 
-        let value: Token = self.consume(
-            Identifier,
+        let value: Expr = self.expression()?;
+
+        self.consume(
+            LeftBrace,
             format!(
-                "Expected variable after 'In' keyword. ({}:{})",
+                "Expected '{{' after the iterable of 'foreach'. ({}:{})",
                 self.tokens[self.current].line, self.tokens[self.current].column
             ),
         )?;
 
-        self.advance();
-
-        self.loop_nesting += 1;
+        self.enter_loop(&label);
 
         let body: Stmt = self.block_statement()?;
 
-        self.loop_nesting -= 1;
+        self.exit_loop(&label);
 
         Ok(Stmt::Iteration {
             var,
             value,
             body: Rc::new(body),
+            label,
         })
     }
 
     fn const_declaration(&mut self) -> NyxInternalParserResult {
-        let mut name: Token = self.consume(
+        let name: Token = self.consume(
             Identifier,
             format!(
                 "Expected variable name. ({}:{})",
@@ -420,8 +445,6 @@ impl<'a> NyxParser<'a> {
             ),
         )?;
 
-        name.lexeme = format!("__const__{}", name.lexeme);
-
         let init: Expr = if self.match_token(Equal) {
             self.expression()?
         } else {
@@ -443,6 +466,10 @@ impl<'a> NyxParser<'a> {
     }
 
     fn let_declaration(&mut self) -> NyxInternalParserResult {
+        if self.match_token(LeftBracket) {
+            return self.destructure_declaration();
+        }
+
         let name: Token = self.consume(
             Identifier,
             format!(
@@ -455,7 +482,6 @@ impl<'a> NyxParser<'a> {
             self.expression()?
         } else {
             Literal {
-                id: self.get_id(),
                 value: LiteralValue::Null,
             }
         };
@@ -471,42 +497,179 @@ impl<'a> NyxParser<'a> {
         Ok(Stmt::Let { name, init })
     }
 
+    fn destructure_declaration(&mut self) -> NyxInternalParserResult {
+        let bracket: Token = self.previous();
+        let mut names: Vec<Token> = Vec::new();
+
+        loop {
+            names.push(self.consume(
+                Identifier,
+                format!(
+                    "Expected variable name in destructuring. ({}:{})",
+                    self.tokens[self.current].line, self.tokens[self.current].column
+                ),
+            )?);
+
+            if !self.match_token(Comma) {
+                break;
+            }
+        }
+
+        self.consume(
+            RightBracket,
+            format!(
+                "Expected ']' after destructured names. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+
+        self.consume(
+            Equal,
+            format!(
+                "Expected '=' after destructured names. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+
+        let init: Expr = self.expression()?;
+
+        self.consume(
+            Semicolon,
+            format!(
+                "Expected ';' after variable declaration. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+
+        Ok(Stmt::Destructure {
+            names,
+            bracket,
+            init,
+        })
+    }
+
     fn statement(&mut self) -> NyxInternalParserResult {
         if self.match_token(Write) {
-            return self.write_statement();
+            return self.write_statement(true);
+        } else if self.match_token(Print) {
+            return self.write_statement(false);
         } else if self.match_token(LeftBrace) {
             return self.block_statement();
         } else if self.match_token(If) {
             return self.if_statement();
         } else if self.match_token(Elif) {
-            return self.elif_statement();
+            let elif: Token = self.previous();
+
+            return Err(format!(
+                "Expected 'if' before 'elif'. ({}:{})",
+                elif.line, elif.column
+            ));
         } else if self.match_token(While) {
-            return self.while_statement();
+            return self.while_statement(None);
         } else if self.match_token(For) {
-            return self.for_statement();
+            return self.for_statement(None);
         } else if self.match_token(ForEach) {
-            return self.foreach_statement();
+            return self.foreach_statement(None);
+        } else if self.check(Identifier)
+            && self
+                .tokens
+                .get(self.current + 1)
+                .is_some_and(|token| token.token_type == Colon)
+        {
+            return self.labeled_statement();
         } else if self.match_token(Return) {
             return self.return_statement();
         } else if self.match_token(Break) {
             return self.break_statement();
         } else if self.match_token(Continue) {
             return self.continue_statement();
+        } else if self.match_token(Try) {
+            return self.try_statement();
+        } else if self.match_token(Throw) {
+            return self.throw_statement();
         }
 
         self.expression_statement()
     }
 
-    fn break_statement(&mut self) -> NyxInternalParserResult {
-        if self.loop_nesting == 0 {
-            PanicHandler::new(
-                Some(self.tokens[self.current].line),
-                Some(self.tokens[self.current].column),
-                Some(&self.tokens[self.current].lexeme),
-                "'break' disallowed outside of loop.",
-            )
-            .panic();
+    fn labeled_statement(&mut self) -> NyxInternalParserResult {
+        let label: Token = self.advance();
+
+        self.advance();
+
+        if self.labels.contains(&label.lexeme) {
+            return Err(format!(
+                "The loop label ({}) is already in use. ({}:{})",
+                label.lexeme, label.line, label.column
+            ));
+        }
+
+        if self.match_token(While) {
+            return self.while_statement(Some(label));
+        } else if self.match_token(For) {
+            return self.for_statement(Some(label));
+        } else if self.match_token(ForEach) {
+            return self.foreach_statement(Some(label));
+        }
+
+        Err(format!(
+            "Expected a loop after the label ({}). ({}:{})",
+            label.lexeme, label.line, label.column
+        ))
+    }
+
+    fn jump_label(&mut self) -> Option<Token> {
+        if !self.check(Identifier) {
+            return None;
+        }
+
+        let label: Token = self.advance();
+
+        if !self.labels.contains(&label.lexeme) {
+            self.errors.push(format!(
+                "Unknown loop label ({}). ({}:{})",
+                label.lexeme, label.line, label.column
+            ));
+        }
+
+        Some(label)
+    }
+
+    fn enter_loop(&mut self, label: &Option<Token>) {
+        self.loop_nesting += 1;
+
+        if let Some(label) = label {
+            self.labels.push(label.lexeme.clone());
+        }
+    }
+
+    fn exit_loop(&mut self, label: &Option<Token>) {
+        self.loop_nesting -= 1;
+
+        if label.is_some() {
+            self.labels.pop();
         }
+    }
+
+    fn enter_function(&mut self) -> (u16, Vec<String>) {
+        self.return_nesting += 1;
+
+        (
+            mem::take(&mut self.loop_nesting),
+            mem::take(&mut self.labels),
+        )
+    }
+
+    fn exit_function(&mut self, (loop_nesting, labels): (u16, Vec<String>)) {
+        self.return_nesting -= 1;
+        self.loop_nesting = loop_nesting;
+        self.labels = labels;
+    }
+
+    fn break_statement(&mut self) -> NyxInternalParserResult {
+        let keyword: Token = self.previous();
+        let label: Option<Token> = self.jump_label();
+
         self.consume(
             Semicolon,
             format!(
@@ -515,21 +678,20 @@ impl<'a> NyxParser<'a> {
             ),
         )?;
 
-        Ok(Stmt::Break {
-            keyword: self.peek(),
-        })
+        if self.loop_nesting == 0 && label.is_none() {
+            self.errors.push(format!(
+                "'break' disallowed outside of loop. ({}:{})",
+                keyword.line, keyword.column
+            ));
+        }
+
+        Ok(Stmt::Break { label })
     }
 
     fn continue_statement(&mut self) -> NyxInternalParserResult {
-        if self.loop_nesting == 0 {
-            PanicHandler::new(
-                Some(self.tokens[self.current].line),
-                Some(self.tokens[self.current].column),
-                Some(&self.tokens[self.current].lexeme),
-                "continue outside of loop.",
-            )
-            .panic();
-        }
+        let keyword: Token = self.previous();
+        let label: Option<Token> = self.jump_label();
+
         self.consume(
             Semicolon,
             format!(
@@ -538,23 +700,26 @@ impl<'a> NyxParser<'a> {
             ),
         )?;
 
-        Ok(Stmt::Continue {
-            keyword: self.peek(),
-        })
+        if self.loop_nesting == 0 && label.is_none() {
+            self.errors.push(format!(
+                "continue outside of loop. ({}:{})",
+                keyword.line, keyword.column
+            ));
+        }
+
+        Ok(Stmt::Continue { label })
     }
 
     fn return_statement(&mut self) -> NyxInternalParserResult {
+        let keyword: Token = self.previous();
+
         if self.return_nesting == 0 {
-            PanicHandler::new(
-                Some(self.tokens[self.current].line),
-                Some(self.tokens[self.current].column),
-                Some(&self.tokens[self.current].lexeme),
-                "'return' disallowed outside of function.",
-            )
-            .panic();
+            self.errors.push(format!(
+                "'return' disallowed outside of function. ({}:{})",
+                keyword.line, keyword.column
+            ));
         }
 
-        let keyword: Token = self.previous();
         let value: Option<Expr> = if !self.check(Semicolon) {
             Some(self.expression()?)
         } else {
@@ -572,7 +737,7 @@ impl<'a> NyxParser<'a> {
         Ok(Stmt::Return { keyword, value })
     }
 
-    fn for_statement(&mut self) -> NyxInternalParserResult {
+    fn for_statement(&mut self, label: Option<Token>) -> NyxInternalParserResult {
         self.consume(
             LeftParen,
             format!(
@@ -617,22 +782,17 @@ impl<'a> NyxParser<'a> {
             ),
         )?;
 
-        self.loop_nesting += 1;
+        self.enter_loop(&label);
 
         let mut body: Stmt = self.statement()?;
 
-        self.loop_nesting -= 1;
+        self.exit_loop(&label);
 
-        if let Some(incr) = increment {
-            body = Stmt::Block {
-                statements: vec![body, Stmt::Expression { expr: incr }],
-            };
-        }
+        let els: Option<Rc<Stmt>> = self.loop_else()?;
 
         let cond: Expr = match condition {
             Some(expr) => expr,
             None => Expr::Literal {
-                id: self.get_id(),
                 value: LiteralValue::True,
             },
         };
@@ -640,6 +800,9 @@ impl<'a> NyxParser<'a> {
         body = Stmt::While {
             condition: cond,
             body: Rc::new(body),
+            increment,
+            els,
+            label,
         };
 
         if let Some(init) = initializer {
@@ -651,7 +814,80 @@ impl<'a> NyxParser<'a> {
         Ok(body)
     }
 
-    fn while_statement(&mut self) -> NyxInternalParserResult {
+    fn try_statement(&mut self) -> NyxInternalParserResult {
+        self.consume(
+            LeftBrace,
+            format!(
+                "Expected '{{' after 'try'. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+
+        let body: Stmt = self.block_statement()?;
+
+        self.consume(
+            Catch,
+            format!(
+                "Expected 'catch' after the 'try' block. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+        self.consume(
+            LeftParen,
+            format!(
+                "Expected '(' after 'catch'. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+
+        let catch_var: Token = self.consume(
+            Identifier,
+            format!(
+                "Expected the error variable name. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+
+        self.consume(
+            RightParen,
+            format!(
+                "Expected ')' after the error variable. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+        self.consume(
+            LeftBrace,
+            format!(
+                "Expected '{{' after 'catch'. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+
+        let catch_body: Stmt = self.block_statement()?;
+
+        Ok(Stmt::Try {
+            body: Rc::new(body),
+            catch_var,
+            catch_body: Rc::new(catch_body),
+        })
+    }
+
+    fn throw_statement(&mut self) -> NyxInternalParserResult {
+        let keyword: Token = self.previous();
+        let value: Expr = self.expression()?;
+
+        self.consume(
+            Semicolon,
+            format!(
+                "Expected ';' after the thrown value. ({}:{})",
+                self.tokens[self.current].line, self.tokens[self.current].column
+            ),
+        )?;
+
+        Ok(Stmt::Throw { keyword, value })
+    }
+
+    fn while_statement(&mut self, label: Option<Token>) -> NyxInternalParserResult {
         self.consume(
             LeftParen,
             format!(
@@ -668,18 +904,31 @@ impl<'a> NyxParser<'a> {
             ),
         )?;
 
-        self.loop_nesting += 1;
+        self.enter_loop(&label);
 
         let body: Stmt = self.statement()?;
 
-        self.loop_nesting -= 1;
+        self.exit_loop(&label);
+
+        let els: Option<Rc<Stmt>> = self.loop_else()?;
 
         Ok(Stmt::While {
             condition,
             body: Rc::new(body),
+            increment: None,
+            els,
+            label,
         })
     }
 
+    fn loop_else(&mut self) -> Result<Option<Rc<Stmt>>, String> {
+        if self.match_token(Else) {
+            return Ok(Some(Rc::new(self.statement()?)));
+        }
+
+        Ok(None)
+    }
+
     fn elif_statement(&mut self) -> NyxInternalParserResult {
         self.consume(
             LeftParen,
@@ -698,8 +947,13 @@ impl<'a> NyxParser<'a> {
         )?;
 
         let then: Rc<Stmt> = Rc::new(self.statement()?);
+        let els: Option<Rc<Stmt>> = self.else_branch()?;
 
-        Ok(Stmt::Elif { predicate, then })
+        Ok(Stmt::Elif {
+            predicate,
+            then,
+            els,
+        })
     }
 
     fn if_statement(&mut self) -> NyxInternalParserResult {
@@ -720,13 +974,13 @@ impl<'a> NyxParser<'a> {
         )?;
 
         let then: Rc<Stmt> = Rc::new(self.statement()?);
-        let elf: Option<Rc<Stmt>> = if self.check(Elif) {
-            Some(Rc::new(self.statement()?))
+        let elf: Option<Rc<Stmt>> = if self.match_token(Elif) {
+            Some(Rc::new(self.elif_statement()?))
         } else {
             None
         };
-        let els: Option<Rc<Stmt>> = if self.match_token(Else) {
-            Some(Rc::new(self.statement()?))
+        let els: Option<Rc<Stmt>> = if elf.is_none() {
+            self.else_branch()?
         } else {
             None
         };
@@ -739,6 +993,16 @@ impl<'a> NyxParser<'a> {
         })
     }
 
+    fn else_branch(&mut self) -> Result<Option<Rc<Stmt>>, String> {
+        if self.match_token(Elif) {
+            return Ok(Some(Rc::new(self.elif_statement()?)));
+        } else if self.match_token(Else) {
+            return Ok(Some(Rc::new(self.statement()?)));
+        }
+
+        Ok(None)
+    }
+
     fn block_statement(&mut self) -> NyxInternalParserResult {
         let mut statements: Vec<Stmt> = vec![];
 
@@ -758,7 +1022,7 @@ impl<'a> NyxParser<'a> {
         Ok(Stmt::Block { statements })
     }
 
-    fn write_statement(&mut self) -> NyxInternalParserResult {
+    fn write_statement(&mut self, newline: bool) -> NyxInternalParserResult {
         let mut exprs: Vec<Expr> = Vec::new();
 
         exprs.push(self.expression()?);
@@ -775,7 +1039,7 @@ impl<'a> NyxParser<'a> {
             ),
         )?;
 
-        Ok(Stmt::Write { exprs })
+        Ok(Stmt::Write { exprs, newline })
     }
 
     fn expression_statement(&mut self) -> NyxInternalParserResult {
@@ -838,7 +1102,12 @@ impl<'a> NyxParser<'a> {
             ),
         )?;
 
-        let body: Vec<Stmt> = match self.block_statement()? {
+        let enclosing: (u16, Vec<String>) = self.enter_function();
+        let body: Result<Stmt, String> = self.block_statement();
+
+        self.exit_function(enclosing);
+
+        let body: Vec<Stmt> = match body? {
             Stmt::Block { statements } => statements,
             _ => panic!("Block statement parsed something that was not a block."),
         };
@@ -972,7 +1241,7 @@ impl<'a> NyxParser<'a> {
 
     fn factor(&mut self) -> Result<Expr, String> {
         let mut expr: Expr = self.unary()?;
-        while self.match_tokens(&[Slash, Star]) {
+        while self.match_tokens(&[Slash, Star, Arith]) {
             let op: Token = self.previous();
             let rhs: Expr = self.unary()?;
             expr = Binary {
@@ -995,45 +1264,59 @@ impl<'a> NyxParser<'a> {
                 operator: op,
                 right: Rc::from(rhs),
             });
-        } else if self.match_next_tokens(&[PlusPlus, MinusMinus]) {
-            let op: Token = self.advance();
+        } else if self.match_tokens(&[PlusPlus, MinusMinus]) {
+            let op: Token = self.previous();
+            let target: Expr = self.unary()?;
 
-            let tk_type: TokenType = match self.advance().token_type {
-                TokenType::PlusPlus => TokenType::Plus,
-                TokenType::MinusMinus => TokenType::Minus,
-                _ => {
-                    return Err(format!(
-                        "Expected '++' or '--'. ({}:{})",
-                        self.tokens[self.current].line, self.tokens[self.current].column
-                    ))
-                }
-            };
+            return self.update(op, target, true);
+        }
+
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Expr, String> {
+        let expr: Expr = self.postfix()?;
 
-            return Ok(Assign {
+        if self.match_token(StarStar) {
+            let op: Token = self.previous();
+            let rhs: Expr = self.unary()?;
+
+            return Ok(Binary {
                 id: self.get_id(),
-                name: op.clone(),
-                value: Rc::from(Binary {
-                    id: self.get_id(),
-                    left: Rc::new(Expr::Variable {
-                        id: self.get_id(),
-                        name: op.clone(),
-                    }),
-                    operator: Token {
-                        token_type: tk_type,
-                        lexeme: "".to_string(),
-                        literal: None,
-                        line: 0,
-                        column: 0,
-                    },
-                    right: Rc::new(Expr::Literal {
-                        id: self.get_id(),
-                        value: LiteralValue::Number(1.0),
-                    }),
-                }),
+                left: Rc::from(expr),
+                operator: op,
+                right: Rc::from(rhs),
             });
         }
 
-        self.call()
+        Ok(expr)
+    }
+
+    fn postfix(&mut self) -> Result<Expr, String> {
+        let expr: Expr = self.call()?;
+
+        if self.match_tokens(&[PlusPlus, MinusMinus]) {
+            let op: Token = self.previous();
+            return self.update(op, expr, false);
+        }
+
+        Ok(expr)
+    }
+
+    fn update(&mut self, operator: Token, target: Expr, prefix: bool) -> Result<Expr, String> {
+        if !matches!(target, Expr::Variable { .. } | Get { .. }) {
+            return Err(format!(
+                "The operator ({}) can only be applied to a variable or a field. ({}:{})",
+                operator.lexeme, operator.line, operator.column
+            ));
+        }
+
+        Ok(Expr::Update {
+            id: self.get_id(),
+            operator,
+            target: Rc::new(target),
+            prefix,
+        })
     }
 
     fn call(&mut self) -> Result<Expr, String> {
@@ -1042,6 +1325,24 @@ impl<'a> NyxParser<'a> {
         loop {
             if self.match_token(LeftParen) {
                 expr = self.finish_call(expr, None)?;
+            } else if self.match_token(LeftBracket) {
+                let bracket: Token = self.previous();
+                let index: Expr = self.expression()?;
+
+                self.consume(
+                    RightBracket,
+                    format!(
+                        "Expected ']' after index. ({}:{})",
+                        self.tokens[self.current].line, self.tokens[self.current].column
+                    ),
+                )?;
+
+                expr = Expr::Index {
+                    id: self.get_id(),
+                    object: Rc::new(expr),
+                    bracket,
+                    index: Rc::new(index),
+                };
             } else if self.match_token(Dot) {
                 let name: Token = self.consume(
                     Identifier,
@@ -1055,6 +1356,19 @@ impl<'a> NyxParser<'a> {
                     object: Rc::new(expr),
                     name,
                 };
+            } else if self.match_token(QuestionDot) {
+                let name: Token = self.consume(
+                    Identifier,
+                    format!(
+                        "Expected property name after '?.'. ({}:{})",
+                        self.tokens[self.current].line, self.tokens[self.current].column
+                    ),
+                )?;
+                expr = Expr::OptionalGet {
+                    id: self.get_id(),
+                    object: Rc::new(expr),
+                    name,
+                };
             } else {
                 break;
             }
@@ -1065,12 +1379,32 @@ impl<'a> NyxParser<'a> {
 
     fn finish_call(&mut self, call: Expr, module: Option<String>) -> Result<Expr, String> {
         let mut arguments: Vec<Expr> = vec![];
+        let mut named: Vec<(Token, Expr)> = vec![];
 
         if !self.check(RightParen) {
             loop {
-                let arg: Expr = self.expression()?;
-                arguments.push(arg);
-                if arguments.len() >= 255 {
+                if self.check(Identifier) && self.tokens[self.current + 1].token_type == Colon {
+                    let name: Token = self.advance();
+                    self.advance();
+
+                    if named.iter().any(|(other, _)| other.lexeme == name.lexeme) {
+                        return Err(format!(
+                            "The argument ({}) is named more than once. ({}:{})",
+                            name.lexeme, name.line, name.column
+                        ));
+                    }
+
+                    named.push((name, self.expression()?));
+                } else if let Some((name, _)) = named.last() {
+                    return Err(format!(
+                        "Positional arguments cannot follow the named argument ({}). ({}:{})",
+                        name.lexeme, name.line, name.column
+                    ));
+                } else {
+                    arguments.push(self.expression()?);
+                }
+
+                if arguments.len() + named.len() >= 255 {
                     return Err(format!(
                         "Cant have more than 255 arguments. ({}:{})",
                         self.tokens[self.current].line, self.tokens[self.current].column
@@ -1099,6 +1433,7 @@ impl<'a> NyxParser<'a> {
                 call: Rc::new(call),
                 paren,
                 arguments,
+                named,
             }),
 
             None => Ok(Call {
@@ -1107,6 +1442,7 @@ impl<'a> NyxParser<'a> {
                 call: Rc::new(call),
                 paren,
                 arguments,
+                named,
             }),
         }
     }
@@ -1133,6 +1469,19 @@ impl<'a> NyxParser<'a> {
 
             LeftBracket => {
                 self.advance();
+
+                let mut elements: Vec<Expr> = Vec::new();
+
+                if !self.check(RightBracket) {
+                    loop {
+                        elements.push(self.expression()?);
+
+                        if !self.match_token(Comma) {
+                            break;
+                        }
+                    }
+                }
+
                 self.consume(
                     RightBracket,
                     format!(
@@ -1141,16 +1490,22 @@ impl<'a> NyxParser<'a> {
                     ),
                 )?;
 
-                Expr::Literal {
-                    id: self.get_id(),
-                    value: LiteralValue::List(Vec::new()),
+                if elements.is_empty() {
+                    Expr::Literal {
+                        value: LiteralValue::List(Vec::new()),
+                    }
+                } else {
+                    Expr::List {
+                        id: self.get_id(),
+                        bracket: tk,
+                        elements,
+                    }
                 }
             }
 
             False | True | Null | Number | StringLit => {
                 self.advance();
                 Literal {
-                    id: self.get_id(),
                     value: LiteralValue::from_token(tk),
                 }
             }
@@ -1172,18 +1527,9 @@ impl<'a> NyxParser<'a> {
                         ),
                     )?;
 
-                    if self.peek().token_type == TokenType::Semicolon {
-                        return Ok(Expr::ModuleProperty {
-                            id: self.get_id(),
-                            module: module.lexeme.to_string(),
-                            name,
-                        });
-                    } else if self.advance().token_type == TokenType::LeftParen {
-                        let new_id: usize = self.get_id();
-
+                    if self.match_token(TokenType::LeftParen) {
                         let call: Expr = self.finish_call(
                             Expr::Literal {
-                                id: new_id,
                                 value: LiteralValue::StringValue(name.lexeme.to_string()),
                             },
                             Some(module.lexeme),
@@ -1191,6 +1537,12 @@ impl<'a> NyxParser<'a> {
 
                         return Ok(call);
                     }
+
+                    return Ok(Expr::ModuleProperty {
+                        id: self.get_id(),
+                        module: module.lexeme.to_string(),
+                        name,
+                    });
                 }
 
                 Variable {
@@ -1258,28 +1610,6 @@ impl<'a> NyxParser<'a> {
         Err(msg)
     }
 
-    fn peek_next(&mut self) -> &Token {
-        &self.tokens[self.current + 1]
-    }
-
-    fn check_next(&mut self, typ: TokenType) -> bool {
-        if self.is_at_end() {
-            return false;
-        }
-
-        self.peek_next().token_type == typ
-    }
-
-    fn match_next_tokens(&mut self, tk: &[TokenType]) -> bool {
-        for tt in tk {
-            if self.check_next(*tt) {
-                return true;
-            }
-        }
-
-        false
-    }
-
     fn check(&mut self, typ: TokenType) -> bool {
         self.peek().token_type == typ
     }
@@ -1343,7 +1673,7 @@ impl<'a> NyxParser<'a> {
             }
 
             match self.peek().token_type {
-                Clazz | Fc | Let | For | If | While | Write | Return => return,
+                Clazz | Fc | Let | For | If | While | Write | Print | Return => return,
                 _ => (),
             }
 
@@ -1353,17 +1683,122 @@ impl<'a> NyxParser<'a> {
 
     fn std_md(&self) -> HashMap<&str, Vec<&str>> {
         HashMap::from([
-            ("os", vec!["exit", "current_time", "input", "name", "arch"]),
-            ("math", vec!["sqrt", "E", "PI", "TAU", "pow"]),
+            (
+                "os",
+                vec![
+                    "exit",
+                    "current_time",
+                    "input",
+                    "terminal_size",
+                    "name",
+                    "arch",
+                    "getcwd",
+                    "chdir",
+                    "sleep",
+                    "home_dir",
+                    "join_path",
+                ],
+            ),
+            (
+                "math",
+                vec![
+                    "sqrt",
+                    "E",
+                    "PI",
+                    "TAU",
+                    "SQRT2",
+                    "LN2",
+                    "LN10",
+                    "INFINITY",
+                    "NEG_INFINITY",
+                    "pow",
+                    "is_infinite",
+                    "approx_eq",
+                    "round_half_even",
+                    "mod_pow",
+                    "is_nan",
+                    "is_finite",
+                ],
+            ),
             (
                 "list",
-                vec!["new", "add", "size", "reverse", "get", "pop", "remove"],
+                vec![
+                    "new",
+                    "add",
+                    "size",
+                    "reverse",
+                    "get",
+                    "pop",
+                    "remove",
+                    "windows",
+                    "map",
+                    "filter",
+                    "reduce",
+                    "join_deep",
+                    "sort",
+                    "join",
+                    "frequencies",
+                    "insert",
+                    "range",
+                    "scan",
+                    "max_index",
+                    "min_index",
+                    "flatten",
+                    "concat",
+                ],
+            ),
+            (
+                "utils",
+                vec![
+                    "type",
+                    "parse",
+                    "copy_fields",
+                    "ensure",
+                    "sort_keys",
+                    "format_number",
+                    "assert",
+                    "assert_eq",
+                    "freeze",
+                    "is_null",
+                    "is_number",
+                    "is_string",
+                    "is_bool",
+                    "is_list",
+                    "is_callable",
+                    "to_bool",
+                    "to_int",
+                    "fields",
+                ],
+            ),
+            (
+                "color",
+                vec!["red", "green", "yellow", "blue", "bold", "rgb"],
             ),
-            ("utils", vec!["type", "parse"]),
+            ("fs", vec!["read_file", "write_file", "exists", "temp_dir"]),
+            ("json", vec!["stringify", "parse"]),
+            ("time", vec!["now_millis", "now_secs", "format"]),
             (
                 "string",
                 vec![
-                    "length", "split", "find", "push", "replace", "trim", "trim_l", "trim_r",
+                    "length",
+                    "split",
+                    "find",
+                    "push",
+                    "replace",
+                    "trim",
+                    "trim_l",
+                    "trim_r",
+                    "codepoints",
+                    "contains",
+                    "starts_with",
+                    "ends_with",
+                    "substring",
+                    "repeat",
+                    "visible_length",
+                    "swap_case",
+                    "expand_tabs",
+                    "fit",
+                    "char_at",
                 ],
             ),
         ])
@@ -1,6 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use super::{expr::Expr, panic::PanicHandler, stmt::Stmt, tokenizer::Token, types::NyxResult};
+use super::{
+    expr::{Expr, LiteralValue},
+    libraries::builtins::Builtins,
+    panic::PanicHandler,
+    stmt::Stmt,
+    tokenizer::Token,
+    types::NyxResult,
+};
 
 #[derive(Copy, Clone, PartialEq)]
 enum FunctionType {
@@ -13,6 +20,16 @@ pub struct Resolver {
     scopes: Vec<HashMap<String, bool>>,
     locals: HashMap<usize, usize>,
     fc: FunctionType,
+    pure_fcs: HashSet<String>,
+    pure: Option<(Token, usize)>,
+    globals: HashSet<String>,
+    repl: bool,
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Resolver {
@@ -21,14 +38,42 @@ impl Resolver {
             scopes: Vec::new(),
             locals: HashMap::new(),
             fc: FunctionType::None,
+            pure_fcs: HashSet::new(),
+            pure: None,
+            globals: Builtins::gen_tree_methods()
+                .into_keys()
+                .map(String::from)
+                .collect(),
+            repl: false,
         }
     }
 
-    fn resolve_internal(&mut self, stmt: &Stmt) -> NyxResult {
+    pub fn with_globals(globals: Vec<String>) -> Self {
+        let mut resolver: Resolver = Self::new();
+
+        resolver.globals.extend(globals);
+
+        resolver
+    }
+
+    pub fn for_repl(globals: Vec<String>) -> Self {
+        let mut resolver: Resolver = Self::with_globals(globals);
+
+        resolver.repl = true;
+
+        resolver
+    }
+
+    fn resolve_internal(&mut self, stmt: &Stmt) -> NyxResult<'_> {
         match stmt {
             Stmt::Block { .. } => self.resolve_block(stmt)?,
             Stmt::Let { .. } => self.resolve_extr_var(stmt)?,
             Stmt::Const { .. } => self.resolve_extr_var(stmt)?,
+            Stmt::Destructure { names, init, .. } => {
+                names.iter().try_for_each(|name| self.declare(name))?;
+                self.resolve_expr(init)?;
+                names.iter().for_each(|name| self.define(name));
+            }
             Stmt::Clazz {
                 name,
                 methods,
@@ -77,8 +122,15 @@ impl Resolver {
             }
             Stmt::Function { .. } => self.resolve_function(stmt, FunctionType::Function)?,
             Stmt::Expression { expr } => self.resolve_expr(expr)?,
-            Stmt::If { .. } => self.resolve_if_stmt(stmt)?,
-            Stmt::Write { exprs } => {
+            Stmt::If { .. } | Stmt::Elif { .. } => self.resolve_if_stmt(stmt)?,
+            Stmt::Write { exprs, .. } => {
+                if let Some((fc, _)) = &self.pure {
+                    return Err(format!(
+                        "Pure function ({}) cannot write output. ({}:{})",
+                        fc.lexeme, fc.line, fc.column
+                    ));
+                }
+
                 exprs.iter().try_for_each(|expr| self.resolve_expr(expr))?;
             }
             Stmt::Return { keyword, value } => {
@@ -93,31 +145,92 @@ impl Resolver {
                     self.resolve_expr(value)?;
                 }
             }
-            Stmt::While { condition, body } => {
+            Stmt::While {
+                condition,
+                body,
+                increment,
+                els,
+                ..
+            } => {
                 self.resolve_expr(condition)?;
                 self.resolve_internal(body.as_ref())?;
+
+                if let Some(increment) = increment {
+                    self.resolve_expr(increment)?;
+                }
+
+                if let Some(els) = els {
+                    self.resolve_internal(els.as_ref())?;
+                }
+            }
+            Stmt::Iteration {
+                var, value, body, ..
+            } => {
+                self.resolve_expr(value)?;
+                self.define(var);
+                self.resolve_internal(body.as_ref())?;
             }
+            Stmt::Try {
+                body,
+                catch_var,
+                catch_body,
+            } => {
+                self.resolve_internal(body.as_ref())?;
+
+                self.begin_scope();
+                self.declare(catch_var)?;
+                self.define(catch_var);
+                self.resolve_internal(catch_body.as_ref())?;
+                self.end_scope();
+            }
+            Stmt::Throw { keyword: _, value } => self.resolve_expr(value)?,
+            Stmt::Std { module, fc } => Self::std_names(module, fc)
+                .into_iter()
+                .for_each(|name| self.bind(name)),
 
             _ => return Ok(()),
         }
         Ok(())
     }
 
-    fn resolve_many(&mut self, stmts: &[Stmt]) {
-        stmts.iter().for_each(|stmt| {
-            let _ = self.resolve_internal(stmt);
-        });
+    fn resolve_many(&mut self, stmts: &[Stmt]) -> NyxResult<'_> {
+        stmts
+            .iter()
+            .try_for_each(|stmt| self.resolve_internal(stmt))
     }
 
     pub fn resolve(mut self, stmts: &[Stmt]) -> Result<HashMap<usize, usize>, String> {
-        self.resolve_many(stmts);
+        stmts.iter().for_each(|stmt| {
+            if let Stmt::Function {
+                name, pure: true, ..
+            } = stmt
+            {
+                self.pure_fcs.insert(name.lexeme.to_string());
+            }
+
+            match stmt {
+                Stmt::Function { name, .. }
+                | Stmt::Clazz { name, .. }
+                | Stmt::Let { name, .. }
+                | Stmt::Const { name, .. } => {
+                    self.globals.insert(name.lexeme.to_string());
+                }
+                Stmt::Destructure { names, .. } => names.iter().for_each(|name| {
+                    self.globals.insert(name.lexeme.to_string());
+                }),
+                Stmt::Std { module, fc } => self.globals.extend(Self::std_names(module, fc)),
+                _ => {}
+            }
+        });
+
+        self.resolve_many(stmts)?;
         Ok(self.locals)
     }
 
-    fn resolve_block(&mut self, stmt: &Stmt) -> NyxResult {
+    fn resolve_block(&mut self, stmt: &Stmt) -> NyxResult<'_> {
         if let Stmt::Block { statements } = stmt {
             self.begin_scope();
-            self.resolve_many(statements.as_slice());
+            self.resolve_many(statements.as_slice())?;
             self.end_scope();
         } else {
             PanicHandler::new(None, None, None, "Uknown type in code block.").panic();
@@ -126,7 +239,7 @@ impl Resolver {
         Ok(())
     }
 
-    fn resolve_extr_var(&mut self, stmt: &Stmt) -> NyxResult {
+    fn resolve_extr_var(&mut self, stmt: &Stmt) -> NyxResult<'_> {
         if let Stmt::Let { name, init } = stmt {
             self.declare(name)?;
             self.resolve_expr(init)?;
@@ -142,13 +255,31 @@ impl Resolver {
         Ok(())
     }
 
-    fn resolve_function(&mut self, stmt: &Stmt, fn_type: FunctionType) -> NyxResult {
-        if let Stmt::Function { name, params, body } = stmt {
+    fn resolve_function(&mut self, stmt: &Stmt, fn_type: FunctionType) -> NyxResult<'_> {
+        if let Stmt::Function {
+            name,
+            params,
+            body,
+            pure,
+        } = stmt
+        {
             self.declare(name)?;
             self.define(name);
-            self.resolve_function_helper(params, body.iter().as_slice(), fn_type)?;
 
-            return Ok(());
+            if !*pure {
+                return self.resolve_function_helper(params, body.iter().as_slice(), fn_type);
+            }
+
+            self.pure_fcs.insert(name.lexeme.to_string());
+
+            let enclosing_pure: Option<(Token, usize)> =
+                self.pure.replace((name.clone(), self.scopes.len()));
+            let rs: NyxResult =
+                self.resolve_function_helper(params, body.iter().as_slice(), fn_type);
+
+            self.pure = enclosing_pure;
+
+            return rs;
         }
 
         PanicHandler::new(None, None, None, "Uknown type in function statement.").panic();
@@ -156,7 +287,7 @@ impl Resolver {
         Ok(())
     }
 
-    fn resolve_if_stmt(&mut self, stmt: &Stmt) -> NyxResult {
+    fn resolve_if_stmt(&mut self, stmt: &Stmt) -> NyxResult<'_> {
         if let Stmt::If {
             predicate,
             then,
@@ -178,6 +309,22 @@ impl Resolver {
             return Ok(());
         }
 
+        if let Stmt::Elif {
+            predicate,
+            then,
+            els,
+        } = stmt
+        {
+            self.resolve_expr(predicate)?;
+            self.resolve_internal(then)?;
+
+            if let Some(els) = els {
+                self.resolve_internal(els)?;
+            }
+
+            return Ok(());
+        }
+
         PanicHandler::new(None, None, None, "Uknown type in if statement.").panic();
 
         Ok(())
@@ -188,7 +335,7 @@ impl Resolver {
         params: &[Token],
         body: &[Stmt],
         resolving_function: FunctionType,
-    ) -> NyxResult {
+    ) -> NyxResult<'_> {
         let enclosing_fc: FunctionType = self.fc;
 
         self.fc = resolving_function;
@@ -202,7 +349,7 @@ impl Resolver {
             rs
         })?;
 
-        self.resolve_many(body);
+        self.resolve_many(body)?;
         self.end_scope();
         self.fc = enclosing_fc;
 
@@ -221,29 +368,51 @@ impl Resolver {
         });
     }
 
-    fn declare(&mut self, name: &Token) -> NyxResult {
-        let size: usize = self.scopes.len();
+    fn declare(&mut self, name: &Token) -> NyxResult<'_> {
+        if let Some(scope) = self.scopes.last_mut() {
+            if scope.contains_key(&name.lexeme) {
+                return Err(format!(
+                    "The variable ({}) is already declared in this scope. ({}:{})",
+                    name.lexeme, name.line, name.column
+                ));
+            }
 
-        if !self.scopes.is_empty() && !self.scopes[size - 1].contains_key(&name.lexeme.to_string())
-        {
-            self.scopes[size - 1].insert(name.lexeme.to_string(), false);
-            return Ok(());
+            scope.insert(name.lexeme.to_string(), false);
         }
 
         Ok(())
     }
 
     fn define(&mut self, name: &Token) {
-        if !self.scopes.is_empty() {
-            let size: usize = self.scopes.len();
-            self.scopes[size - 1].insert(name.lexeme.to_string(), true);
+        self.bind(name.lexeme.to_string());
+    }
+
+    fn bind(&mut self, name: String) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, true);
+            return;
         }
+
+        self.globals.insert(name);
     }
 
-    fn resolve_expr(&mut self, expr: &Expr) -> NyxResult {
+    fn std_names(module: &str, fc: &Option<Vec<String>>) -> Vec<String> {
+        match fc {
+            Some(names) => names.clone(),
+            None => vec![module.to_string()],
+        }
+    }
+
+    fn resolve_expr(&mut self, expr: &Expr) -> NyxResult<'_> {
         match expr {
-            Expr::Variable { id, name: _ } => self.resolve_let(expr, *id),
-            Expr::Assign { id, .. } => self.resolve_assign(expr, *id),
+            Expr::Variable { id, name } => {
+                self.check_pure_read(name)?;
+                self.resolve_let(expr, *id)
+            }
+            Expr::Assign { id, name, .. } => {
+                self.check_pure_assign(name)?;
+                self.resolve_assign(expr, *id)
+            }
             Expr::Binary {
                 id: _,
                 left,
@@ -255,26 +424,43 @@ impl Resolver {
             }
             Expr::Call {
                 id: _,
-                module: _,
+                module,
                 call,
-                paren: _,
+                paren,
                 arguments,
+                named,
             } => {
+                self.check_pure_call(module, call, paren)?;
                 self.resolve_expr(call.as_ref())?;
 
-                arguments.iter().for_each(|arg| {
-                    let _ = self.resolve_expr(arg);
-                });
-
-                Ok(())
+                arguments
+                    .iter()
+                    .try_for_each(|arg| self.resolve_expr(arg))?;
+                named.iter().try_for_each(|(_, arg)| self.resolve_expr(arg))
             }
             Expr::Get {
                 id: _,
                 object,
                 name: _,
+            }
+            | Expr::OptionalGet {
+                id: _,
+                object,
+                name: _,
             } => self.resolve_expr(object),
             Expr::Grouping { id: _, expression } => self.resolve_expr(expression),
-            Expr::Literal { id: _, value: _ } => Ok(()),
+            Expr::Index { object, index, .. } => {
+                self.resolve_expr(object)?;
+                self.resolve_expr(index)
+            }
+            Expr::Literal { value: _ } => Ok(()),
+            Expr::List {
+                id: _,
+                bracket: _,
+                elements,
+            } => elements
+                .iter()
+                .try_for_each(|element| self.resolve_expr(element)),
             Expr::Logical {
                 id: _,
                 left,
@@ -287,9 +473,16 @@ impl Resolver {
             Expr::Set {
                 id: _,
                 object,
-                name: _,
+                name,
                 value,
             } => {
+                if let Some((fc, _)) = &self.pure {
+                    return Err(format!(
+                        "Pure function ({}) cannot mutate the field ({}). ({}:{})",
+                        fc.lexeme, name.lexeme, name.line, name.column
+                    ));
+                }
+
                 self.resolve_expr(value)?;
                 self.resolve_expr(object)
             }
@@ -328,6 +521,22 @@ impl Resolver {
                 operator: _,
                 right,
             } => self.resolve_expr(right),
+            Expr::Update { target, .. } => {
+                match target.as_ref() {
+                    Expr::Variable { name, .. } => self.check_pure_assign(name)?,
+                    Expr::Get { name, .. } => {
+                        if let Some((fc, _)) = &self.pure {
+                            return Err(format!(
+                                "Pure function ({}) cannot mutate the field ({}). ({}:{})",
+                                fc.lexeme, name.lexeme, name.line, name.column
+                            ));
+                        }
+                    }
+                    _ => {}
+                }
+
+                self.resolve_expr(target)
+            }
             Expr::AnonFunction {
                 id: _,
                 paren: _,
@@ -343,7 +552,7 @@ impl Resolver {
         }
     }
 
-    fn resolve_let(&mut self, expr: &Expr, resolve_id: usize) -> NyxResult {
+    fn resolve_let(&mut self, expr: &Expr, resolve_id: usize) -> NyxResult<'_> {
         match expr {
             Expr::Variable { id: _, name } => {
                 if !self.scopes.is_empty() {
@@ -365,6 +574,7 @@ impl Resolver {
                 call,
                 paren: _,
                 arguments: _,
+                named: _,
             } => match call.as_ref() {
                 Expr::Variable { id: _, name } => self.resolve_local(name, resolve_id),
                 _ => {
@@ -393,20 +603,23 @@ impl Resolver {
         }
     }
 
-    fn resolve_local(&mut self, name: &Token, resolve_id: usize) -> NyxResult {
-        if !self.scopes.is_empty() {
-            for i in 0..=(self.scopes.len() - 1) {
-                if self.scopes[i].contains_key(&name.lexeme.to_string()) {
-                    self.locals.insert(resolve_id, self.scopes.len() - 1 - i);
-                    return Ok(());
-                }
-            }
+    fn resolve_local(&mut self, name: &Token, resolve_id: usize) -> NyxResult<'_> {
+        if let Some(i) = self.local_scope(name) {
+            self.locals.insert(resolve_id, self.scopes.len() - 1 - i);
+            return Ok(());
         }
 
-        Ok(())
+        if self.globals.contains(&name.lexeme) || (self.repl && self.fc != FunctionType::None) {
+            return Ok(());
+        }
+
+        Err(format!(
+            "The variable ({}) has not been declared. ({}:{})",
+            name.lexeme, name.line, name.column
+        ))
     }
 
-    fn resolve_assign(&mut self, expr: &Expr, rs_id: usize) -> NyxResult {
+    fn resolve_assign(&mut self, expr: &Expr, rs_id: usize) -> NyxResult<'_> {
         if let Expr::Assign { id: _, name, value } = expr {
             self.resolve_expr(value)?;
             self.resolve_local(name, rs_id)?;
@@ -417,4 +630,105 @@ impl Resolver {
 
         Ok(())
     }
+
+    fn local_scope(&self, name: &Token) -> Option<usize> {
+        self.scopes
+            .iter()
+            .rposition(|scope| scope.contains_key(&name.lexeme))
+    }
+
+    fn check_pure_read(&self, name: &Token) -> NyxResult<'_> {
+        if let Some((fc, base)) = &self.pure {
+            if self.local_scope(name).is_some_and(|i| i >= *base)
+                || self.pure_fcs.contains(&name.lexeme)
+            {
+                return Ok(());
+            }
+
+            return Err(format!(
+                "Pure function ({}) can only read its parameters and local variables, not ({}). ({}:{})",
+                fc.lexeme, name.lexeme, name.line, name.column
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_pure_assign(&self, name: &Token) -> NyxResult<'_> {
+        if self.pure_fcs.contains(&name.lexeme) && self.local_scope(name).is_none() {
+            return Err(format!(
+                "Cannot assign the pure function ({}). ({}:{})",
+                name.lexeme, name.line, name.column
+            ));
+        }
+
+        if let Some((fc, base)) = &self.pure {
+            if self.local_scope(name).is_some_and(|i| i >= *base) {
+                return Ok(());
+            }
+
+            return Err(format!(
+                "Pure function ({}) cannot assign the outer variable ({}). ({}:{})",
+                fc.lexeme, name.lexeme, name.line, name.column
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_pure_call(
+        &self,
+        module: &Option<String>,
+        call: &Expr,
+        paren: &Token,
+    ) -> NyxResult<'_> {
+        let Some((fc, _)) = &self.pure else {
+            return Ok(());
+        };
+
+        match (module, call) {
+            (
+                Some(module),
+                Expr::Literal {
+                    value: LiteralValue::StringValue(method),
+                },
+            ) => {
+                let pure: bool = match module.as_str() {
+                    "math" | "string" => true,
+                    "list" => !["map", "filter", "reduce", "scan"].contains(&method.as_str()),
+                    "utils" => [
+                        "type",
+                        "parse",
+                        "format_number",
+                        "is_null",
+                        "is_number",
+                        "is_string",
+                        "is_bool",
+                        "is_list",
+                        "is_callable",
+                        "to_bool",
+                        "to_int",
+                    ]
+                    .contains(&method.as_str()),
+                    _ => false,
+                };
+
+                if pure {
+                    return Ok(());
+                }
+
+                Err(format!(
+                    "Pure function ({}) cannot call ({}::{}) of the standard library. ({}:{})",
+                    fc.lexeme, module, method, paren.line, paren.column
+                ))
+            }
+            (None, Expr::Variable { id: _, name }) if self.pure_fcs.contains(&name.lexeme) => {
+                Ok(())
+            }
+            (_, _) => Err(format!(
+                "Pure function ({}) can only call other pure functions. ({}:{})",
+                fc.lexeme, paren.line, paren.column
+            )),
+        }
+    }
 }
@@ -9,6 +9,7 @@ pub enum Stmt {
     },
     Write {
         exprs: Vec<Expr>,
+        newline: bool,
     },
     Let {
         name: Token,
@@ -18,6 +19,11 @@ pub enum Stmt {
         name: Token,
         init: Expr,
     },
+    Destructure {
+        names: Vec<Token>,
+        bracket: Token,
+        init: Expr,
+    },
     Block {
         statements: Vec<Stmt>,
     },
@@ -35,15 +41,20 @@ pub enum Stmt {
     Elif {
         predicate: Expr,
         then: Rc<Stmt>,
+        els: Option<Rc<Stmt>>,
     },
     While {
         condition: Expr,
         body: Rc<Stmt>,
+        increment: Option<Expr>,
+        els: Option<Rc<Stmt>>,
+        label: Option<Token>,
     },
     Function {
         name: Token,
         params: Vec<Token>,
         body: Vec<Stmt>,
+        pure: bool,
     },
 
     Return {
@@ -56,16 +67,240 @@ pub enum Stmt {
     },
 
     Break {
-        keyword: Token,
+        label: Option<Token>,
     },
 
     Continue {
-        keyword: Token,
+        label: Option<Token>,
     },
 
     Iteration {
         var: Token,
-        value: Token,
+        value: Expr,
+        body: Rc<Stmt>,
+        label: Option<Token>,
+    },
+
+    Try {
         body: Rc<Stmt>,
+        catch_var: Token,
+        catch_body: Rc<Stmt>,
     },
+
+    Throw {
+        keyword: Token,
+        value: Expr,
+    },
+}
+
+impl Stmt {
+    pub fn convert(&self, indent: usize) -> String {
+        let pad: String = "  ".repeat(indent);
+
+        match self {
+            Stmt::Expression { expr } => format!("{pad}(expr {})", expr.convert()),
+            Stmt::Write { exprs, newline } => format!(
+                "{pad}({} {})",
+                if *newline { "write" } else { "print" },
+                exprs
+                    .iter()
+                    .map(|e| e.convert())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+            Stmt::Let { name, init } => format!("{pad}(let {} {})", name.lexeme, init.convert()),
+            Stmt::Const { name, init } => {
+                format!("{pad}(const {} {})", name.lexeme, init.convert())
+            }
+            Stmt::Destructure {
+                names,
+                bracket: _,
+                init,
+            } => format!(
+                "{pad}(let [{}] {})",
+                names
+                    .iter()
+                    .map(|n| n.lexeme.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                init.convert()
+            ),
+            Stmt::Block { statements } => Self::nest(
+                "block".to_string(),
+                statements.iter().map(|s| s.convert(indent + 1)).collect(),
+                indent,
+            ),
+            Stmt::Clazz {
+                name,
+                methods,
+                superclass,
+            } => Self::nest(
+                match superclass {
+                    Some(Expr::Variable { id: _, name: sc }) => {
+                        format!("clazz {} extends {}", name.lexeme, sc.lexeme)
+                    }
+                    _ => format!("clazz {}", name.lexeme),
+                },
+                methods.iter().map(|m| m.convert(indent + 1)).collect(),
+                indent,
+            ),
+            Stmt::If {
+                predicate,
+                then,
+                elf,
+                els,
+            } => {
+                let mut children: Vec<String> = vec![then.convert(indent + 1)];
+
+                if let Some(elf) = elf {
+                    children.push(elf.convert(indent + 1));
+                }
+
+                if let Some(els) = els {
+                    children.push(Self::nest(
+                        "else".to_string(),
+                        vec![els.convert(indent + 2)],
+                        indent + 1,
+                    ));
+                }
+
+                Self::nest(format!("if {}", predicate.convert()), children, indent)
+            }
+            Stmt::Elif {
+                predicate,
+                then,
+                els,
+            } => {
+                let mut children: Vec<String> = vec![then.convert(indent + 1)];
+
+                if let Some(els) = els {
+                    children.push(match els.as_ref() {
+                        Stmt::Elif { .. } => els.convert(indent + 1),
+                        _ => Self::nest(
+                            "else".to_string(),
+                            vec![els.convert(indent + 2)],
+                            indent + 1,
+                        ),
+                    });
+                }
+
+                Self::nest(format!("elif {}", predicate.convert()), children, indent)
+            }
+            Stmt::While {
+                condition,
+                body,
+                increment,
+                els,
+                label,
+            } => {
+                let mut children: Vec<String> = vec![body.convert(indent + 1)];
+
+                if let Some(increment) = increment {
+                    children.push(format!(
+                        "{}(step {})",
+                        "  ".repeat(indent + 1),
+                        increment.convert()
+                    ));
+                }
+
+                if let Some(els) = els {
+                    children.push(Self::nest(
+                        "else".to_string(),
+                        vec![els.convert(indent + 2)],
+                        indent + 1,
+                    ));
+                }
+
+                Self::nest(
+                    format!("{}while {}", Self::label(label), condition.convert()),
+                    children,
+                    indent,
+                )
+            }
+            Stmt::Function {
+                name,
+                params,
+                body,
+                pure,
+            } => Self::nest(
+                format!(
+                    "{}fc {}({})",
+                    if *pure { "pure " } else { "" },
+                    name.lexeme,
+                    params
+                        .iter()
+                        .map(|p| p.lexeme.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                body.iter().map(|s| s.convert(indent + 1)).collect(),
+                indent,
+            ),
+            Stmt::Return { keyword: _, value } => match value {
+                Some(value) => format!("{pad}(return {})", value.convert()),
+                None => format!("{pad}(return)"),
+            },
+            Stmt::Std { module, fc } => match fc {
+                Some(fc) => format!("{pad}(lib std::{module} [{}])", fc.join(", ")),
+                None => format!("{pad}(lib std::{module})"),
+            },
+            Stmt::Break { label } => match label {
+                Some(label) => format!("{pad}(break {})", label.lexeme),
+                None => format!("{pad}(break)"),
+            },
+            Stmt::Continue { label } => match label {
+                Some(label) => format!("{pad}(continue {})", label.lexeme),
+                None => format!("{pad}(continue)"),
+            },
+            Stmt::Iteration {
+                var,
+                value,
+                body,
+                label,
+            } => Self::nest(
+                format!(
+                    "{}foreach {} in {}",
+                    Self::label(label),
+                    var.lexeme,
+                    value.convert()
+                ),
+                vec![body.convert(indent + 1)],
+                indent,
+            ),
+            Stmt::Try {
+                body,
+                catch_var,
+                catch_body,
+            } => Self::nest(
+                "try".to_string(),
+                vec![
+                    body.convert(indent + 1),
+                    Self::nest(
+                        format!("catch {}", catch_var.lexeme),
+                        vec![catch_body.convert(indent + 2)],
+                        indent + 1,
+                    ),
+                ],
+                indent,
+            ),
+            Stmt::Throw { keyword: _, value } => format!("{pad}(throw {})", value.convert()),
+        }
+    }
+
+    fn label(label: &Option<Token>) -> String {
+        match label {
+            Some(label) => format!("{}: ", label.lexeme),
+            None => String::new(),
+        }
+    }
+
+    fn nest(header: String, children: Vec<String>, indent: usize) -> String {
+        let pad: String = "  ".repeat(indent);
+
+        if children.is_empty() {
+            return format!("{pad}({header})");
+        }
+
+        format!("{pad}({header}\n{})", children.join("\n"))
+    }
 }
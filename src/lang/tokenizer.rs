@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use super::{
     panic::PanicHandler,
-    types::NyxAnalyzeResult,
+    types::{NyxAnalyzeResult, NyxResult},
     utils::{is_alpha, is_digit},
 };
 
@@ -27,10 +27,10 @@ impl<'a> NyxTokenizer<'a> {
         }
     }
 
-    pub fn analyze(&mut self) -> NyxAnalyzeResult {
+    pub fn analyze(&mut self) -> NyxAnalyzeResult<'_> {
         while !self.is_at_end() {
             self.start = self.current;
-            self.scan();
+            self.scan()?;
         }
 
         self.tokens.push(Token {
@@ -44,7 +44,7 @@ impl<'a> NyxTokenizer<'a> {
         Ok(&self.tokens)
     }
 
-    fn scan(&mut self) {
+    fn scan(&mut self) -> NyxResult<'_> {
         match self.advance() {
             b'[' => self.make(TokenType::LeftBracket, None),
             b']' => self.make(TokenType::RightBracket, None),
@@ -72,19 +72,34 @@ impl<'a> NyxTokenizer<'a> {
             }
             b'%' => self.make(TokenType::Arith, None),
             b';' => self.make(TokenType::Semicolon, None),
-            b'*' => self.make(TokenType::Star, None),
+            b'*' => {
+                let tk: TokenType = if self.char_match(b'*') {
+                    TokenType::StarStar
+                } else {
+                    TokenType::Star
+                };
+                self.make(tk, None);
+            }
             b':' => {
                 let tk: TokenType = if self.char_match(b':') {
                     TokenType::ColonColon
                 } else {
-                    PanicHandler::new(
+                    TokenType::Colon
+                };
+
+                self.make(tk, None);
+            }
+            b'?' => {
+                let tk: TokenType = if self.char_match(b'.') {
+                    TokenType::QuestionDot
+                } else {
+                    return Err(PanicHandler::new(
                         Some(self.line),
                         Some(self.current),
                         Some(self.source_error()),
-                        "Expected other ':'.",
+                        "Expected '.' after '?'.",
                     )
-                    .panic();
-                    TokenType::Null
+                    .format());
                 };
 
                 self.make(tk, None);
@@ -133,20 +148,23 @@ impl<'a> NyxTokenizer<'a> {
                         self.advance();
                     }
                 } else if self.char_match(b'*') {
-                    loop {
-                        if self.is_at_end() || self.char_match(b'*') && self.char_match(b'/') {
-                            break;
-                        }
-                        self.advance();
+                    let mut depth: usize = 1;
 
-                        if self.is_at_end() && self.previous() != b'*' || self.previous() == b'/' {
-                            PanicHandler::new(
+                    while depth > 0 {
+                        if self.is_at_end() {
+                            return Err(PanicHandler::new(
                                 Some(self.line),
-                                Some(self.current),
-                                Some(self.source_error()),
-                                "Incomplete multiline comment.",
+                                Some(self.start),
+                                Some(&self.source_code[self.start..self.start + 2]),
+                                "Unterminated multiline comment.",
                             )
-                            .panic();
+                            .format());
+                        }
+
+                        match self.advance() {
+                            b'/' if self.char_match(b'*') => depth += 1,
+                            b'*' if self.char_match(b'/') => depth -= 1,
+                            _ => {}
                         }
                     }
                 } else {
@@ -155,54 +173,69 @@ impl<'a> NyxTokenizer<'a> {
             }
             b'|' => {
                 if self.char_match(b'|') {
-                    return self.make(TokenType::Or, None);
+                    self.make(TokenType::Or, None);
+                    return Ok(());
                 }
 
-                PanicHandler::new(
+                return Err(PanicHandler::new(
                     Some(self.line),
                     Some(self.current),
                     Some(self.source_error()),
                     "Expected other '|'.",
                 )
-                .panic();
+                .format());
             }
 
             b'&' => {
                 if self.char_match(b'&') {
-                    return self.make(TokenType::And, None);
+                    self.make(TokenType::And, None);
+                    return Ok(());
                 }
 
-                PanicHandler::new(
+                return Err(PanicHandler::new(
                     Some(self.line),
                     Some(self.current),
                     Some(self.source_error()),
                     "Expected other '&'.",
                 )
-                .panic();
+                .format());
             }
             b' ' | b'\r' | b'\t' => {}
             b'\n' => self.line += 1,
-            b'"' => self.string(),
+            b'"' => self.string()?,
             c => {
                 if is_digit(c) {
                     return self.number();
                 } else if is_alpha(c) {
-                    return self.identifier();
+                    self.identifier();
+                    return Ok(());
+                } else if let Some(ch) = self.char_at(self.start).filter(|_| !c.is_ascii()) {
+                    self.current = self.start + ch.len_utf8();
+
+                    if ch.is_alphabetic() {
+                        self.identifier();
+                        return Ok(());
+                    }
                 }
-                PanicHandler::new(
+                return Err(PanicHandler::new(
                     Some(self.line),
                     Some(self.current),
                     Some(self.source_error()),
                     "Strange char.",
                 )
-                .panic();
+                .format());
             }
         }
+
+        Ok(())
     }
 
     fn identifier(&mut self) {
-        while is_alpha(self.peek()) || is_digit(self.peek()) {
-            self.advance();
+        while let Some(ch) = self
+            .char_at(self.current)
+            .filter(|ch| *ch == '_' || ch.is_alphanumeric())
+        {
+            self.current += ch.len_utf8();
         }
 
         if let Some(&tk_type) = self.keywords.get(self.lexeme()) {
@@ -213,30 +246,127 @@ impl<'a> NyxTokenizer<'a> {
         self.make(TokenType::Identifier, None);
     }
 
-    fn number(&mut self) {
-        while is_digit(self.peek()) {
+    fn number(&mut self) -> NyxResult<'_> {
+        if self.lexeme() == "0" {
+            match self.peek() {
+                b'x' | b'X' => return self.radix_number(16),
+                b'o' | b'O' => return self.radix_number(8),
+                b'b' | b'B' => return self.radix_number(2),
+                _ => {}
+            }
+        }
+
+        while is_digit(self.peek()) || self.peek() == b'_' {
             self.advance();
         }
-        if self.peek() == b'.' && is_digit(self.peek_next()) {
+        if self.peek() == b'.' && (is_digit(self.peek_next()) || self.peek_next() == b'_') {
             self.advance();
 
-            while is_digit(self.peek()) {
+            while is_digit(self.peek()) || self.peek() == b'_' {
                 self.advance();
             }
         }
+        if matches!(self.peek(), b'e' | b'E') {
+            self.advance();
 
-        match self.lexeme().parse::<f64>() {
-            Ok(v) => self.make(TokenType::Number, Some(LiteralValue::FValue(v))),
-            Err(_) => {
-                PanicHandler::new(
+            if matches!(self.peek(), b'+' | b'-') {
+                self.advance();
+            }
+
+            if !is_digit(self.peek()) {
+                let message: String = format!(
+                    "Expected digits in the exponent of the number ({}).",
+                    self.lexeme()
+                );
+
+                return Err(PanicHandler::new(
                     Some(self.line),
-                    Some(self.current),
+                    Some(self.start),
                     Some(self.source_error()),
-                    "Could not is to correct number.",
+                    &message,
                 )
-                .panic();
+                .format());
+            }
+
+            while is_digit(self.peek()) {
+                self.advance();
             }
         }
+
+        let lexeme: &str = self.lexeme();
+
+        match self.strip_separators(lexeme)?.parse::<f64>() {
+            Ok(v) => {
+                self.make(TokenType::Number, Some(LiteralValue::FValue(v)));
+                Ok(())
+            }
+            Err(_) => Err(PanicHandler::new(
+                Some(self.line),
+                Some(self.current),
+                Some(self.source_error()),
+                "Could not is to correct number.",
+            )
+            .format()),
+        }
+    }
+
+    fn radix_number(&mut self, radix: u32) -> NyxResult<'_> {
+        self.advance();
+
+        while self.peek().is_ascii_alphanumeric() || self.peek() == b'_' {
+            self.advance();
+        }
+
+        let lexeme: &str = self.lexeme();
+        let digits: String = self.strip_separators(&lexeme[2..])?;
+
+        let message: String = if digits.is_empty() {
+            format!("Expected digits after ({}).", &lexeme[..2])
+        } else if let Some(digit) = digits.chars().find(|c| !c.is_digit(radix)) {
+            format!(
+                "Invalid digit ({}) for a base {} number ({}).",
+                digit, radix, lexeme
+            )
+        } else {
+            match i64::from_str_radix(&digits, radix) {
+                Ok(v) => {
+                    self.make(TokenType::Number, Some(LiteralValue::FValue(v as f64)));
+                    return Ok(());
+                }
+                Err(_) => format!("The number ({}) is too large.", lexeme),
+            }
+        };
+
+        Err(PanicHandler::new(
+            Some(self.line),
+            Some(self.start),
+            Some(self.source_error()),
+            &message,
+        )
+        .format())
+    }
+
+    fn strip_separators(&mut self, digits: &str) -> Result<String, String> {
+        if digits.contains("__")
+            || digits
+                .split('.')
+                .any(|part| part.starts_with('_') || part.ends_with('_'))
+        {
+            let message: String = format!(
+                "Misplaced digit separator '_' in the number ({}).",
+                self.lexeme()
+            );
+
+            return Err(PanicHandler::new(
+                Some(self.line),
+                Some(self.start),
+                Some(self.source_error()),
+                &message,
+            )
+            .format());
+        }
+
+        Ok(digits.replace('_', ""))
     }
 
     fn peek_next(&mut self) -> u8 {
@@ -244,42 +374,98 @@ impl<'a> NyxTokenizer<'a> {
             return b'\0';
         }
 
-        self.source_code.chars().nth(self.current + 1).unwrap() as u8
+        self.source_code.as_bytes()[self.current + 1]
     }
 
-    fn string(&mut self) {
+    fn string(&mut self) -> NyxResult<'_> {
         while self.peek() != b'"' && !self.is_at_end() {
             self.advance();
         }
 
         if self.is_at_end() {
-            PanicHandler::new(
+            return Err(PanicHandler::new(
                 Some(self.line),
                 Some(self.current),
                 Some(self.source_error()),
                 "Incomplete string.",
             )
-            .panic();
+            .format());
         }
 
         self.advance();
 
-        let v: String = String::from_utf8(
-            self.source_code.as_bytes()[self.start + 1..self.current - 1].to_vec(),
-        )
-        .unwrap_or_else(|_| {
-            PanicHandler::new(
-                Some(self.line),
-                Some(self.current),
-                Some(self.source_error()),
-                "Unrecognized character of Unicode Code Point.",
-            )
-            .panic();
-
-            String::new()
-        });
+        let v: String =
+            self.unicode_escapes(&self.source_code[self.start + 1..self.current - 1])?;
 
         self.make(TokenType::StringLit, Some(LiteralValue::SValue(v)));
+
+        Ok(())
+    }
+
+    fn unicode_escapes(&self, raw: &str) -> Result<String, String> {
+        let mut decoded: String = String::with_capacity(raw.len());
+        let mut cursor: usize = 0;
+
+        while let Some(found) = raw[cursor..].find("\\u") {
+            let escape: usize = cursor + found;
+
+            let error = |message: String| {
+                PanicHandler::new(
+                    Some(self.line),
+                    Some(self.start + 1 + escape),
+                    Some(self.lexeme()),
+                    &message,
+                )
+                .format()
+            };
+
+            decoded.push_str(&raw[cursor..escape]);
+
+            let Some(body) = raw[escape + 2..].strip_prefix('{') else {
+                return Err(error(
+                    "Expected '{' after '\\u' in the unicode escape.".to_string(),
+                ));
+            };
+
+            let Some(close) = body.find('}') else {
+                return Err(error(
+                    "Unterminated unicode escape, expected '}'.".to_string(),
+                ));
+            };
+
+            let digits: &str = &body[..close];
+            let end: usize = escape + 3 + close + 1;
+            let text: &str = &raw[escape..end];
+
+            if digits.is_empty()
+                || digits.len() > 6
+                || !digits.chars().all(|c| c.is_ascii_hexdigit())
+            {
+                return Err(error(format!(
+                    "The unicode escape ({}) must have between 1 and 6 hex digits.",
+                    text
+                )));
+            }
+
+            match u32::from_str_radix(digits, 16)
+                .ok()
+                .and_then(char::from_u32)
+            {
+                Some(c) => decoded.push(c),
+                None => {
+                    return Err(error(format!(
+                        "The unicode escape ({}) is not a valid character.",
+                        text
+                    )))
+                }
+            }
+
+            cursor = end;
+        }
+
+        decoded.push_str(&raw[cursor..]);
+
+        Ok(decoded)
     }
 
     fn peek(&mut self) -> u8 {
@@ -300,22 +486,25 @@ impl<'a> NyxTokenizer<'a> {
     }
 
     fn source_error(&mut self) -> &'a str {
-        if self.is_at_end() {
-            return &self.source_code[self.current..];
+        while ![b'{', b'}', b'\n'].contains(&self.peek()) && !self.is_at_end() {
+            self.advance();
         }
 
-        let mut adv: isize = -1;
+        while !self.source_code.is_char_boundary(self.current) {
+            self.current += 1;
+        }
 
-        while ![b'{', b'}', b'\n'].contains(&self.peek()) && !self.is_at_end() {
-            self.advance();
-            adv += 1;
+        let mut start: usize = self.start.min(self.current);
+
+        while !self.source_code.is_char_boundary(start) {
+            start -= 1;
         }
 
-        &self.source_code[self.start - adv as usize..self.current]
+        &self.source_code[start..self.current]
     }
 
-    fn previous(&self) -> u8 {
-        self.source_code.as_bytes()[self.current - 1]
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.source_code.get(index..)?.chars().next()
     }
 
     fn lexeme(&self) -> &'a str {
@@ -357,7 +546,10 @@ pub enum TokenType {
     Semicolon,
     Slash,
     Star,
+    StarStar,
+    Colon,
     ColonColon,
+    QuestionDot,
     RightBracket,
     LeftBracket,
     Arith,
@@ -392,6 +584,7 @@ pub enum TokenType {
     Null,
     Or,
     Write,
+    Print,
     Return,
     Super,
     This,
@@ -402,6 +595,10 @@ pub enum TokenType {
     Extends,
     Std,
     Lib,
+    Pure,
+    Try,
+    Catch,
+    Throw,
 
     Eof,
 }
@@ -420,6 +617,7 @@ fn keywords<'a>() -> HashMap<&'a str, TokenType> {
         ("null", TokenType::Null),
         ("or", TokenType::Or),
         ("write", TokenType::Write),
+        ("print", TokenType::Print),
         ("return", TokenType::Return),
         ("super", TokenType::Super),
         ("this", TokenType::This),
@@ -431,6 +629,10 @@ fn keywords<'a>() -> HashMap<&'a str, TokenType> {
         ("std", TokenType::Std),
         ("extends", TokenType::Extends),
         ("lib", TokenType::Lib),
+        ("pure", TokenType::Pure),
+        ("try", TokenType::Try),
+        ("catch", TokenType::Catch),
+        ("throw", TokenType::Throw),
         ("continue", TokenType::Continue),
         ("break", TokenType::Break),
     ])
@@ -10,6 +10,30 @@ pub fn is_alpha(ch: u8) -> bool {
     ch.is_ascii_lowercase() || ch.is_ascii_uppercase() || ch == b'_'
 }
 
+pub fn is_balanced(source: &str) -> bool {
+    let mut depth: isize = 0;
+    let mut in_string: bool = false;
+    let mut chars = source.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => in_string = !in_string,
+            '/' if !in_string && chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '{' | '(' | '[' if !in_string => depth += 1,
+            '}' | ')' | ']' if !in_string => depth -= 1,
+            _ => {}
+        }
+    }
+
+    depth <= 0 && !in_string
+}
+
 pub fn formatter(start: bool, end: bool, strings: &[ColoredString]) -> String {
     let mut build_string: String = String::new();
 
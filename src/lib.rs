@@ -0,0 +1,15 @@
+pub mod lang;
+
+pub use lang::{
+    expr::{Expr, LiteralValue},
+    interpreter::NyxInterpreter,
+    parser::NyxParser,
+    resolver::Resolver,
+    stmt::Stmt,
+    tokenizer::NyxTokenizer,
+    Nyx,
+};
+
+pub fn interpret(source: &str) -> Result<(), String> {
+    NyxInterpreter::new().run_source(source)
+}
@@ -1,7 +1,3 @@
-#![cfg(target_arch = "x86_64")]
-
-mod lang;
-
 fn main() {
-    lang::Nyx.run();
+    nyx::Nyx.run();
 }
@@ -0,0 +1,120 @@
+use std::thread::Builder;
+
+use nyx::{interpret, lang::constants::NYX_STACK_SIZE, LiteralValue, NyxInterpreter};
+
+fn with_stack<T: Send + 'static>(run: impl FnOnce() -> T + Send + 'static) -> T {
+    Builder::new()
+        .stack_size(NYX_STACK_SIZE)
+        .spawn(run)
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+#[test]
+fn interpret_runs_a_script() {
+    assert!(interpret("fc double(n) { return n * 2; }\nlet x = double(21);").is_ok());
+}
+
+#[test]
+fn interpret_returns_errors() {
+    let error: String = interpret("let x = 1;\nwrite y;").unwrap_err();
+
+    assert!(error.contains("The variable (y) has not been declared."));
+}
+
+#[test]
+fn run_source_keeps_globals_between_calls() {
+    let mut interpreter: NyxInterpreter = NyxInterpreter::new();
+
+    interpreter
+        .run_source("let count = 1;\nfc bump() { count = count + 1; }")
+        .unwrap();
+    interpreter.run_source("bump();\nbump();").unwrap();
+
+    assert!(interpreter.global("count") == Some(LiteralValue::Number(3.0)));
+}
+
+#[test]
+fn run_source_recovers_from_panics() {
+    let mut interpreter: NyxInterpreter = NyxInterpreter::new();
+    interpreter.catch_panics = true;
+
+    let error: String = interpreter
+        .run_source("lib std::list;\nlist::reverse(1);")
+        .unwrap_err();
+
+    assert!(error.contains("(list::reverse()) First argument must be an list."));
+    assert!(interpreter
+        .run_source("let after = list::reverse([1, 2]);")
+        .is_ok());
+}
+
+#[test]
+fn global_reads_top_level_variables() {
+    let mut interpreter: NyxInterpreter = NyxInterpreter::new();
+
+    interpreter
+        .run_source("let answer = 42;\nfc scope() { let hidden = 1; }\nscope();")
+        .unwrap();
+
+    assert!(interpreter.global("answer") == Some(LiteralValue::Number(42.0)));
+    assert!(interpreter.global("hidden").is_none());
+}
+
+#[test]
+fn runtime_errors_point_at_the_source_line() {
+    let error: String = interpret("let x = 1;\nwrite x();").unwrap_err();
+
+    assert_eq!(
+        error,
+        "(1) is not callable. (2:20)\n\n2 | write x();\n  |         ^"
+    );
+}
+
+#[test]
+fn prelude_is_available_without_imports() {
+    let mut interpreter: NyxInterpreter = NyxInterpreter::new();
+
+    interpreter.run_source("let kind = type(5);").unwrap();
+
+    assert!(interpreter.global("kind") == Some(LiteralValue::StringValue("number".to_string())));
+}
+
+#[test]
+fn runaway_recursion_returns_an_error() {
+    with_stack(|| {
+        let error: String = interpret("fc f(n) { return f(n + 1); }\nf(0);").unwrap_err();
+
+        assert!(error.contains("Maximum recursion depth (1000) exceeded while calling (f)."));
+
+        let mut interpreter: NyxInterpreter = NyxInterpreter::new();
+
+        assert!(interpreter
+            .run_source("fc g() { return g(); }\ng();")
+            .is_err());
+        assert!(interpreter.run_source("let after = 1;").is_ok());
+    });
+}
+
+#[test]
+fn max_depth_belongs_to_each_interpreter() {
+    with_stack(|| {
+        let source: &str = "fc count(n) { if (n == 0) { return 0; } return count(n - 1) + 1; }";
+
+        let mut shallow: NyxInterpreter = NyxInterpreter::new();
+        shallow.max_depth = 5;
+
+        let mut deep: NyxInterpreter = NyxInterpreter::new();
+
+        shallow.run_source(source).unwrap();
+        deep.run_source(source).unwrap();
+
+        assert!(shallow
+            .run_source("count(10);")
+            .unwrap_err()
+            .contains("Maximum recursion depth (5) exceeded while calling (count)."));
+        assert!(deep.run_source("let total = count(100);").is_ok());
+        assert!(deep.global("total") == Some(LiteralValue::Number(100.0)));
+    });
+}
@@ -0,0 +1,287 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn nyx(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_nyx"))
+        .arg("--no-color")
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn nyx_with_stdin(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_nyx"))
+        .arg("--no-color")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn doc_describes_a_function() {
+    let output: Output = nyx(&["doc", "string::split"]);
+    let stdout: String = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Arity: 2"));
+    assert!(stdout.contains(
+        "Splits the string by every occurrence of the separator and returns the parts as a list."
+    ));
+}
+
+#[test]
+fn doc_lists_every_module() {
+    let stdout: String = String::from_utf8(nyx(&["doc"]).stdout).unwrap();
+
+    [
+        "color", "fs", "json", "list", "math", "os", "string", "time", "utils",
+    ]
+    .iter()
+    .for_each(|module| assert!(stdout.contains(&format!("{}:", module))));
+}
+
+#[test]
+fn doc_rejects_unknown_entries() {
+    assert!(!nyx(&["doc", "string::nope"]).status.success());
+}
+
+#[test]
+fn ast_fold_replaces_constant_expressions() {
+    let path = std::env::temp_dir().join("nyx_fold_test.nx");
+    std::fs::write(
+        &path,
+        "const SECONDS = 60 * 60;\nlet x = 2;\nwrite x * 60;\n",
+    )
+    .unwrap();
+
+    let stdout: String =
+        String::from_utf8(nyx(&["ast", "--fold", path.to_str().unwrap()]).stdout).unwrap();
+
+    assert!(stdout.contains("(const SECONDS 3600)"));
+    assert!(stdout.contains("(write (* (let x) 60))"));
+}
+
+#[test]
+fn ast_fold_and_check_only_fold_bounded_expressions() {
+    let path = std::env::temp_dir().join("nyx_bounded_fold_test.nx");
+    std::fs::write(
+        &path,
+        "pure fc spin(n) {\n    while (true) {}\n}\n\nif (false) {\n    spin(1);\n}\n\nconst BIG = \"ab\" * 30000000;\n",
+    )
+    .unwrap();
+
+    let output: Output = nyx(&["ast", "--fold", path.to_str().unwrap()]);
+    let stdout: String = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout.contains("(call (let spin) 1)"));
+    assert!(stdout.contains("(const BIG (* ab 30000000))"));
+
+    let output: Output = nyx(&["check", path.to_str().unwrap()]);
+
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("No errors found."));
+}
+
+#[test]
+fn run_does_not_fold_calls_that_allocate_too_much() {
+    let path = std::env::temp_dir().join("nyx_allocating_fold_test.nx");
+    std::fs::write(
+        &path,
+        "lib std::list;\n\npure fc f(n) {\n    return list::range(0, n);\n}\n\nif (false) {\n    write f(1e9);\n    write f(4000000);\n}\n\nwrite \"done\";\n",
+    )
+    .unwrap();
+
+    let output: Output = nyx(&["run", path.to_str().unwrap()]);
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "done\n");
+    assert!(output.stderr.is_empty());
+
+    let stdout: String =
+        String::from_utf8(nyx(&["ast", "--fold", path.to_str().unwrap()]).stdout).unwrap();
+
+    assert!(stdout.contains("(call (let f) 4000000)"));
+}
+
+#[test]
+fn check_reports_jumps_outside_of_loops() {
+    let path = std::env::temp_dir().join("nyx_check_jumps_test.nx");
+    std::fs::write(&path, "break;\nwrite 1;\ncontinue;\n").unwrap();
+
+    let output: Output = nyx(&["check", path.to_str().unwrap()]);
+    let stderr: String = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("error: 'break' disallowed outside of loop. (1:5)"));
+    assert!(stderr.contains("error: continue outside of loop. (3:24)"));
+    assert!(stderr.contains("2 error(s) found."));
+    assert!(!stderr.contains("panicked"));
+}
+
+#[test]
+fn check_keeps_loop_labels_inside_their_function() {
+    let path = std::env::temp_dir().join("nyx_check_labels_test.nx");
+    std::fs::write(
+        &path,
+        "outer: while (true) {\n    let f = fc() { break outer; };\n    f();\n}\n",
+    )
+    .unwrap();
+
+    let output: Output = nyx(&["check", path.to_str().unwrap()]);
+    let stderr: String = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("error: Unknown loop label (outer)."));
+    assert!(stderr.contains("1 error(s) found."));
+}
+
+#[test]
+fn repl_keeps_the_session_after_errors() {
+    let output: Output = nyx_with_stdin(&["repl"], include_str!("repl.nx"));
+    let stdout: String = String::from_utf8(output.stdout).unwrap();
+    let stderr: String = String::from_utf8(output.stderr).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(stdout, "10\n15\n11\n11\n1\n2\n");
+    assert!(stderr.contains("Expected variable name. (1:5)"));
+    assert!(stderr.contains("(list::get()) Index must be an integer"));
+    assert!(stderr.contains("A Callable should not be used as a boolean value."));
+    assert!(stderr.contains("'return' disallowed outside of function."));
+    assert!(!stderr.contains("panicked"));
+    assert!(!stdout.contains("Unreachable"));
+}
+
+#[test]
+fn terminal_size_is_null_without_a_terminal() {
+    let path = std::env::temp_dir().join("nyx_terminal_size_test.nx");
+    std::fs::write(&path, "lib std::os;\nwrite os::terminal_size();\n").unwrap();
+
+    let output: Output = Command::new(env!("CARGO_BIN_EXE_nyx"))
+        .args(["--no-color", "run", path.to_str().unwrap()])
+        .env("COLUMNS", "120")
+        .env("LINES", "40")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "null\n");
+}
+
+#[test]
+fn tokens_dumps_the_token_stream() {
+    let path = std::env::temp_dir().join("nyx_tokens_test.nx");
+    std::fs::write(&path, "let a = 1;\n").unwrap();
+
+    let output: Output = nyx(&["tokens", path.to_str().unwrap()]);
+    let stdout: String = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout.lines().collect::<Vec<&str>>(),
+        [
+            "Let 'let' (1:3)",
+            "Identifier 'a' (1:5)",
+            "Equal '=' (1:7)",
+            "Number '1' (1:9)",
+            "Semicolon ';' (1:10)",
+            "Eof '' (2:11)",
+        ]
+    );
+}
+
+#[test]
+fn exit_ends_with_the_given_status() {
+    let output: Output = nyx(&["run", "tests/exit.nx"]);
+
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Before exiting.\n"
+    );
+
+    let output: Output = nyx(&["run", "tests/os_exit.nx"]);
+
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Exiting with 3.");
+}
+
+#[test]
+fn exit_rejects_non_integer_codes_like_os_exit() {
+    let path = std::env::temp_dir().join("nyx_exit_test.nx");
+
+    for (source, message) in [
+        (
+            "exit(2.5);",
+            "(exit()) Should must have 1 argument of type integer.",
+        ),
+        (
+            "lib std::os;\nos::exit(2.5);",
+            "(os::exit()) Should must have 1 argument of type integer.",
+        ),
+    ] {
+        std::fs::write(&path, source).unwrap();
+
+        let output: Output = nyx(&["run", path.to_str().unwrap()]);
+        let stderr: String = String::from_utf8(output.stderr).unwrap();
+
+        assert_eq!(output.status.code(), Some(1));
+        assert!(stderr.contains(message));
+        assert!(!stderr.contains("panicked"));
+    }
+}
+
+#[test]
+fn stdlib_type_errors_exit_without_a_panic() {
+    let path = std::env::temp_dir().join("nyx_stdlib_error_test.nx");
+
+    for (source, message) in [
+        (
+            "lib std::list;\nlist::reverse(5);",
+            "(list::reverse()) First argument must be an list.",
+        ),
+        (
+            "lib std::string;\nstring::length(5);",
+            "(string::length()) First argument must be a string.",
+        ),
+        ("lib std::math;\nmath::sqrt(\"nine\");", "(math::sqrt())"),
+        (
+            "lib std::os;\nos::sleep(-1);",
+            "(os::sleep()) Should must have 1 argument of type number greater than or equal to 0.",
+        ),
+    ] {
+        std::fs::write(&path, source).unwrap();
+
+        let output: Output = nyx(&["run", path.to_str().unwrap()]);
+        let stderr: String = String::from_utf8(output.stderr).unwrap();
+
+        assert_eq!(output.status.code(), Some(1));
+        assert!(stderr.contains(message));
+        assert!(!stderr.contains("panicked"));
+    }
+}
+
+#[test]
+fn max_depth_is_capped_to_fit_the_stack() {
+    let output: Output = nyx(&["--max-depth", "1000000", "run", "tests/recursion.nx"]);
+    let stderr: String = String::from_utf8(output.stderr).unwrap();
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("1000000 is not in 1..="));
+}